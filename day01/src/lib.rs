@@ -0,0 +1,103 @@
+use anyhow::Result;
+use runner::{lines, Params, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// One turn of the dial, e.g. `L68`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    direction: Direction,
+    count: i32,
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Input = Vec<Rotation>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(|line| {
+                let split = line
+                    .text
+                    .char_indices()
+                    .nth(1)
+                    .map_or(line.text.len(), |(i, _)| i);
+                let (dir, count) = line.text.split_at(split);
+                let direction = match dir {
+                    "L" => Direction::Left,
+                    "R" => Direction::Right,
+                    _ => return Err(line.error(dir, format!("expected L or R, not {dir:?}"))),
+                };
+                Ok(Rotation {
+                    direction,
+                    count: line.parse(count, "rotation count")?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Input, _params: &Params) -> Result<i32> {
+        let mut zeroes = 0;
+        let mut position = 50;
+        for rotation in data {
+            let mut count_i = rotation.count;
+            if rotation.direction == Direction::Left {
+                count_i = 100 - count_i;
+            }
+
+            position = (position + count_i) % 100;
+
+            if position == 0 {
+                zeroes += 1;
+            }
+        }
+
+        Ok(zeroes)
+    }
+
+    fn part2(data: &Self::Input, _params: &Params) -> Result<i32> {
+        let mut zeroes = 0;
+        let mut position = 50;
+        for rotation in data {
+            let mut count_i = rotation.count;
+            if rotation.direction == Direction::Left {
+                if count_i >= position {
+                    zeroes += (count_i - position) / 100;
+                    if position != 0 {
+                        zeroes += 1
+                    }
+                }
+                count_i = 100 - (count_i % 100);
+            } else if (position + count_i) >= 100 {
+                zeroes += (position + count_i) / 100;
+            }
+
+            position = (position + count_i) % 100;
+        }
+
+        Ok(zeroes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day01::parse("L68\nX30\nR4x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = Day01::parse("L68\nR4x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (2, 2, 2));
+    }
+}
@@ -0,0 +1 @@
+runner::example_tests!(day01::Day01);
@@ -0,0 +1,83 @@
+use anyhow::Result;
+use fancy_regex::Regex;
+use runner::{lines, Params, ParseError, Solution};
+use std::ops::RangeInclusive;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<RangeInclusive<u64>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = lines(input)
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "expected a line of ranges"))?;
+
+        line.text
+            .split(',')
+            .map(|range_s| {
+                let (floor, ceil) = range_s
+                    .split_once('-')
+                    .ok_or_else(|| line.error(range_s, format!("invalid range {range_s:?}")))?;
+                Ok(RangeInclusive::new(
+                    line.parse(floor, "range start")?,
+                    line.parse(ceil, "range end")?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Input, _params: &Params) -> Result<u64> {
+        let mut sum: u64 = 0;
+        for range in data {
+            runner::debug!("Processing range {range:?}");
+
+            for num in range.clone() {
+                let num_s: String = num.to_string();
+                let len = num_s.len();
+                if !len.is_multiple_of(2) {
+                    continue;
+                }
+                let (half_1, half_2) = num_s.split_at(len / 2);
+                if half_1 == half_2 {
+                    sum += num;
+                }
+            }
+        }
+
+        Ok(sum)
+    }
+
+    fn part2(data: &Self::Input, _params: &Params) -> Result<u64> {
+        let re = Regex::new(r"\A(\d+)\1+\z").unwrap();
+        let mut sum: u64 = 0;
+        for range in data {
+            runner::debug!("Processing range {range:?}");
+
+            for num in range.clone() {
+                let num_s: String = num.to_string();
+
+                if re.is_match(&num_s)? {
+                    sum += num;
+                }
+            }
+        }
+
+        Ok(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day02::parse("11-22,95115,998-1012").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (1, 7, 5));
+    }
+}
@@ -0,0 +1 @@
+runner::example_tests!(day02::Day02);
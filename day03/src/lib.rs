@@ -0,0 +1,94 @@
+use anyhow::Result;
+use runner::{lines, Params, ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    /// The joltage of each battery, bank by bank.
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(|line| {
+                line.text
+                    .char_indices()
+                    .map(|(i, battery)| {
+                        battery.to_digit(10).ok_or_else(|| {
+                            let battery_s = &line.text[i..i + battery.len_utf8()];
+                            line.error(battery_s, format!("invalid joltage {battery:?}"))
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Input, _params: &Params) -> Result<u32> {
+        let mut sum: u32 = 0;
+
+        for bank in data {
+            let (mut tens, mut ones) = (0, 0);
+            let mut batteries = bank.iter().peekable();
+            while let Some(&joltage) = batteries.next() {
+                if joltage > tens && batteries.peek().is_some() {
+                    tens = joltage;
+                    ones = 0;
+                } else if joltage > ones {
+                    ones = joltage;
+                }
+            }
+            runner::debug!("Bank top joltage is {tens}{ones}");
+            sum += tens * 10 + ones;
+        }
+
+        Ok(sum)
+    }
+
+    fn part2(data: &Self::Input, _params: &Params) -> Result<u64> {
+        let mut sum: u64 = 0;
+
+        for bank in data {
+            let mut digits: [u32; 12] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+            let batteries_count = &bank.len();
+            for (n, &joltage) in bank.iter().enumerate() {
+                let remaining_batteries_count = batteries_count - n - 1;
+
+                for i in 0..digits.len() {
+                    let unchecked_digits_count = 11 - i;
+                    let has_sufficient_digits = remaining_batteries_count >= unchecked_digits_count;
+
+                    if joltage > digits[i] && has_sufficient_digits {
+                        digits[i] = joltage;
+                        digits[i + 1..].fill(0);
+                        break;
+                    }
+                }
+            }
+            let mut top_joltage: u64 = 0;
+            for (i, digit) in digits.iter().enumerate() {
+                let exp = 11 - i as u32;
+                let base: u64 = 10;
+                let digit_calc: u64 = u64::from(*digit) * base.pow(exp);
+                top_joltage += digit_calc;
+            }
+            sum += top_joltage;
+        }
+
+        Ok(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day03::parse("987654321111111\n81111a111111119\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+    }
+}
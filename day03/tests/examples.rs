@@ -0,0 +1 @@
+runner::example_tests!(day03::Day03);
@@ -0,0 +1,229 @@
+use anyhow::{bail, Result};
+use runner::{lines, Params, ParseError, Solution};
+use std::ops::RangeInclusive;
+
+/// The database: fresh ingredient ID ranges, a blank line, then the
+/// available ingredient IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    fresh_ranges: Vec<RangeInclusive<u64>>,
+    ingredients: Vec<u64>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = Inventory;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut line_iter = lines(input);
+        let mut fresh_ranges: Vec<RangeInclusive<u64>> = Vec::new();
+
+        for line in line_iter.by_ref() {
+            if line.text.is_empty() {
+                break;
+            }
+            let (floor, ceil) = line.split_once('-', "a range like 3-5")?;
+            fresh_ranges.push(RangeInclusive::new(
+                line.parse(floor, "range start")?,
+                line.parse(ceil, "range end")?,
+            ));
+        }
+
+        let ingredients = line_iter
+            .map(|line| line.parse(line.text, "ingredient ID"))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Inventory {
+            fresh_ranges,
+            ingredients,
+        })
+    }
+
+    fn part1(data: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut sum = 0;
+
+        for ingredient in &data.ingredients {
+            for fresh_range in data.fresh_ranges.iter() {
+                if fresh_range.contains(ingredient) {
+                    sum += 1;
+                    break;
+                }
+            }
+        }
+
+        Ok(sum)
+    }
+
+    fn part2(data: &Self::Input, _params: &Params) -> Result<u64> {
+        let mut fresh_ranges: Vec<RangeInclusive<u64>> = Vec::new();
+
+        for range in &data.fresh_ranges {
+            marry_ranges(*range.start(), *range.end(), &mut fresh_ranges);
+        }
+
+        let overlapping_ranges: Vec<RangeInclusive<u64>> = detect_overlapping_ranges(&fresh_ranges);
+        if !overlapping_ranges.is_empty() {
+            bail!("Overlapping ranges! {:?}", overlapping_ranges);
+        }
+
+        Ok(fresh_ranges
+            .iter()
+            .map(|range| range.end() - range.start() + 1)
+            .sum())
+    }
+}
+
+fn marry_ranges(proposed_lower: u64, proposed_upper: u64, ranges: &mut Vec<RangeInclusive<u64>>) {
+    let (mut new_lower_bound, mut new_upper_bound): (u64, u64) = (proposed_lower, proposed_upper);
+    ranges.sort_by(|a, b| a.start().cmp(b.start()));
+    for i in (0..ranges.len()).rev() {
+        let range = &ranges[i];
+        let (range_lower, range_upper) = (*range.start(), *range.end());
+
+        // The proposed range is useless, return early, no-op
+        if range_lower <= new_lower_bound && range_upper >= new_upper_bound {
+            return;
+        }
+
+        // The existing range is useless, delete it
+        if range_lower >= new_lower_bound && range_upper <= new_upper_bound {
+            ranges.remove(i);
+            continue;
+        }
+
+        // Ensure no conflict with lower
+        if new_lower_bound <= range_upper && new_upper_bound > range_upper {
+            new_lower_bound = range_upper + 1;
+        }
+
+        // Ensure no conflict with upper
+        if new_upper_bound >= range_lower && new_lower_bound < range_lower {
+            new_upper_bound = range_lower - 1;
+        }
+    }
+
+    // If we made it this far, we append a new range
+    ranges.push(new_lower_bound..=new_upper_bound);
+}
+
+fn detect_overlapping_ranges(ranges: &[RangeInclusive<u64>]) -> Vec<RangeInclusive<u64>> {
+    let mut overlapping_ranges: Vec<RangeInclusive<u64>> = Vec::new();
+
+    let mut sorted_ranges = ranges.to_vec();
+    sorted_ranges.sort_by(|a, b| a.start().cmp(b.start()));
+
+    let mut iter = sorted_ranges.iter().peekable();
+    while let Some(range) = iter.next() {
+        if let Some(next_range) = iter.peek() {
+            if range.end() >= next_range.start() {
+                overlapping_ranges.push(range.clone());
+                overlapping_ranges.push((*next_range).clone());
+            }
+        }
+    }
+
+    overlapping_ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day05::parse("3-5\n10-1x\n\n1\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (2, 4, "invalid range end \"1x\"")
+        );
+
+        let err = Day05::parse("3-5\n\n1\n5 \n").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+    }
+
+    #[test]
+    fn test_marry_ranges_adjusts_upper() {
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![(4..=8)];
+        let expected_ranges: Vec<RangeInclusive<u64>> = vec![(4..=8), (3..=3)];
+
+        marry_ranges(3, 7, &mut ranges);
+        assert_eq!(ranges, expected_ranges);
+    }
+
+    #[test]
+    fn test_marry_ranges_adjusts_lower() {
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![(4..=8)];
+        let expected_ranges: Vec<RangeInclusive<u64>> = vec![(4..=8), (9..=10)];
+
+        marry_ranges(6, 10, &mut ranges);
+        assert_eq!(ranges, expected_ranges);
+    }
+
+    #[test]
+    fn test_marry_ranges_adjusts_from_both_sides() {
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![(4..=8), (11..=15)];
+        let expected_ranges: Vec<RangeInclusive<u64>> = vec![(4..=8), (11..=15), (9..=10)];
+
+        marry_ranges(6, 13, &mut ranges);
+        assert_eq!(ranges, expected_ranges);
+    }
+
+    #[test]
+    fn test_marry_ranges_does_not_adjust_no_conflict() {
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![(4..=8)];
+        let expected_ranges: Vec<RangeInclusive<u64>> = vec![(4..=8), (10..=12)];
+
+        marry_ranges(10, 12, &mut ranges);
+        assert_eq!(ranges, expected_ranges);
+    }
+
+    #[test]
+    fn test_marry_ranges_removes_existing_redundant_range() {
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![(4..=8)];
+        let expected_ranges: Vec<RangeInclusive<u64>> = vec![(2..=8)];
+
+        marry_ranges(2, 8, &mut ranges);
+        assert_eq!(ranges, expected_ranges);
+    }
+
+    #[test]
+    fn test_marry_ranges_does_not_add_redundant_range() {
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![(4..=8)];
+        let expected_ranges: Vec<RangeInclusive<u64>> = vec![(4..=8)];
+
+        marry_ranges(4, 6, &mut ranges);
+        assert_eq!(ranges, expected_ranges);
+    }
+
+    #[test]
+    fn test_marry_ranges_does_not_add_redundant_range_complex() {
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![(4..=8), (9..=15)];
+        let expected_ranges: Vec<RangeInclusive<u64>> = vec![(4..=8), (9..=15)];
+
+        marry_ranges(5, 12, &mut ranges);
+        assert_eq!(ranges, expected_ranges);
+    }
+
+    #[test]
+    fn test_marry_ranges_fix_bug() {
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![(272731198580506..=273246390457576)];
+        let expected_ranges: Vec<RangeInclusive<u64>> = vec![(272731198580506..=273570669932717)];
+
+        marry_ranges(272731198580506, 273570669932717, &mut ranges);
+        assert_eq!(ranges, expected_ranges);
+    }
+
+    #[test]
+    fn test_marry_ranges_fix_bug2() {
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![(272731198580506..=273570669932717)];
+        let expected_ranges: Vec<RangeInclusive<u64>> = vec![(272731198580506..=273570669932717)];
+
+        marry_ranges(272731198580506, 273246390457576, &mut ranges);
+        assert_eq!(ranges, expected_ranges);
+    }
+}
@@ -0,0 +1 @@
+runner::example_tests!(day05::Day05);
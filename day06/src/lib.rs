@@ -0,0 +1,245 @@
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use runner::{lines, Line, Params, ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    /// Keeps the lines as they are, since part 2 reads the numbers by
+    /// column, but checks that they're rows of digits over a line of
+    /// operators.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let worksheet: Vec<Line> = lines(input).collect();
+        let Some((operators_line, rows)) = worksheet.split_last() else {
+            return Err(ParseError::at_end(
+                input,
+                "expected rows of numbers and a line of operators",
+            ));
+        };
+
+        for row in rows {
+            check_chars(row, |c| c.is_ascii_digit() || c == ' ', "a digit")?;
+            if row.text.trim().is_empty() {
+                return Err(row.error(row.text, "expected a row of numbers"));
+            }
+        }
+        check_chars(
+            operators_line,
+            |c| c == '+' || c == '*' || c == ' ',
+            "+ or *",
+        )?;
+        if operators_line.text.trim().is_empty() {
+            return Err(operators_line.error(operators_line.text, "expected a line of operators"));
+        }
+        check_columns(rows)?;
+
+        Ok(worksheet.iter().map(|line| line.text.to_string()).collect())
+    }
+
+    fn part1(data: &Self::Input, _params: &Params) -> Result<u64> {
+        let mut map: HashMap<usize, Vec<u64>> = HashMap::new();
+        let mut column_outcomes: Vec<u64> = Vec::new();
+        for lines in data {
+            let row_entries = lines.split_whitespace().collect_vec();
+            match row_entries[0].parse::<u64>() {
+                Ok(_) => {
+                    // The line is numeric
+                    for (i, entry) in row_entries.iter().enumerate() {
+                        map.entry(i).or_default().push(entry.parse()?);
+                    }
+                }
+                Err(_) => {
+                    // This is likely the last line of operators
+                    for (i, entry) in row_entries.iter().enumerate() {
+                        let operation = if entry.eq(&"+") { add } else { multiply };
+                        let outcome = map
+                            .get(&i)
+                            .with_context(|| format!("No numbers for problem {}", i + 1))?
+                            .iter()
+                            .copied()
+                            .reduce(operation)
+                            .unwrap();
+                        column_outcomes.push(outcome);
+                    }
+                }
+            }
+        }
+
+        Ok(column_outcomes.into_iter().sum())
+    }
+
+    fn part2(data: &Self::Input, _params: &Params) -> Result<u64> {
+        let (operators_line, rows) = data.split_last().context("The worksheet is empty")?;
+        let mut column_outcomes: Vec<u64> = Vec::new();
+        let operators = operators_line.split_whitespace().collect_vec();
+        let values_map = get_vertical_values(rows.to_vec())?;
+
+        for (i, entry) in operators.iter().enumerate() {
+            let operation = if entry.eq(&"+") { add } else { multiply };
+            let outcome = values_map
+                .get(&i)
+                .with_context(|| format!("No numbers for problem {}", i + 1))?
+                .iter()
+                .copied()
+                .reduce(operation)
+                .unwrap();
+            column_outcomes.push(outcome);
+        }
+
+        Ok(column_outcomes.into_iter().sum())
+    }
+}
+
+/// Fails on the first character of `line` that isn't `allowed`.
+fn check_chars(
+    line: &Line,
+    allowed: impl Fn(char) -> bool,
+    expected: &str,
+) -> Result<(), ParseError> {
+    match line.text.char_indices().find(|&(_, c)| !allowed(c)) {
+        Some((i, c)) => Err(line.error(
+            &line.text[i..i + c.len_utf8()],
+            format!("expected {expected}, not {c:?}"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Part 2 reads every column top to bottom as a single number, so digits
+/// can't pick up again below a space.
+fn check_columns(rows: &[Line]) -> Result<(), ParseError> {
+    let width = rows.iter().map(|row| row.text.len()).max().unwrap_or(0);
+
+    for column in 0..width {
+        let (mut started, mut ended) = (false, false);
+        for row in rows {
+            match row.text.as_bytes().get(column) {
+                Some(c) if c.is_ascii_digit() => {
+                    if ended {
+                        return Err(row.error(
+                            &row.text[column..column + 1],
+                            "expected a space, the number in this column has already ended",
+                        ));
+                    }
+                    started = true;
+                }
+                _ => ended = started,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn add(a: u64, b: u64) -> u64 {
+    a + b
+}
+
+fn multiply(a: u64, b: u64) -> u64 {
+    a * b
+}
+
+fn rows_to_columns(rows: Vec<String>) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+
+    for (r, row) in rows.iter().enumerate() {
+        for (i, c) in row.chars().enumerate() {
+            match columns.get_mut(i) {
+                Some(col) => col.push(c),
+                None => {
+                    columns.push(" ".repeat(r));
+                    columns[i].push(c);
+                }
+            }
+        }
+    }
+
+    columns
+}
+
+fn get_vertical_values(rows: Vec<String>) -> Result<HashMap<usize, Vec<u64>>> {
+    let mut problem_inputs: HashMap<usize, Vec<u64>> = HashMap::new();
+    let mut i = 0;
+
+    for column in rows_to_columns(rows) {
+        if column.trim().is_empty() {
+            i += 1;
+            continue;
+        }
+        problem_inputs
+            .entry(i)
+            .or_default()
+            .push(column.trim().parse()?)
+    }
+
+    Ok(problem_inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/example1.txt");
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day06::parse("123 328\n 45 6-4\n*   +\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+
+        let err = Day06::parse("123 328\n*   -\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+
+    #[test]
+    fn test_parse_rejects_gaps_in_columns() {
+        let err = Day06::parse("1\n 5\n1\n+\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+
+        let err = Day06::parse("12\n1\n12\n+\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 2));
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_operators() {
+        let err = Day06::parse("1 2\n   \n").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.message, "expected a line of operators");
+    }
+
+    #[test]
+    fn test_rows_to_columns() {
+        let expected: Vec<String> = vec![
+            "1  ", "24 ", "356", "   ", "369", "248", "8  ", "   ", " 32", "581", "175", "   ",
+            "623", "431", "  4",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        let mut input = Day06::parse(EXAMPLE).unwrap();
+        input.pop();
+
+        assert_eq!(rows_to_columns(input), expected);
+    }
+
+    #[test]
+    fn test_get_vertical_values() {
+        let mut expected: HashMap<usize, Vec<u64>> = HashMap::new();
+        expected.insert(0, vec![1, 24, 356]);
+        expected.insert(1, vec![369, 248, 8]);
+        expected.insert(2, vec![32, 581, 175]);
+        expected.insert(3, vec![623, 431, 4]);
+
+        let mut input = Day06::parse(EXAMPLE).unwrap();
+        input.pop();
+
+        assert_eq!(get_vertical_values(input).unwrap(), expected)
+    }
+}
@@ -0,0 +1 @@
+runner::example_tests!(day06::Day06);
@@ -0,0 +1,128 @@
+use anyhow::Result;
+use runner::{lines, Params, ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(|line| {
+                match line
+                    .text
+                    .char_indices()
+                    .find(|&(_, c)| !matches!(c, '.' | 'S' | '^'))
+                {
+                    Some((i, c)) => Err(line.error(
+                        &line.text[i..i + c.len_utf8()],
+                        format!("expected ., S or ^, not {c:?}"),
+                    )),
+                    None => Ok(line.text.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut sum: usize = 0;
+        let mut beams: Vec<usize> = Vec::new();
+
+        for line in data {
+            let mut next_beams: Vec<usize> = Vec::new();
+            for (i, c) in line.chars().enumerate() {
+                if c == 'S' {
+                    next_beams = vec![i];
+                    continue;
+                }
+
+                if c == '^' && beams.contains(&i) {
+                    next_beams.push(i - 1);
+                    next_beams.push(i + 1);
+                    sum += 1;
+                    continue;
+                }
+
+                if beams.contains(&i) {
+                    next_beams.push(i);
+                }
+            }
+            beams = next_beams;
+        }
+
+        Ok(sum)
+    }
+
+    fn part2(data: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut beams: HashMap<usize, usize> = HashMap::new();
+
+        for (n, line) in data.iter().enumerate() {
+            let line_length = line.chars().count();
+            if n % 2 == 1 {
+                if !runner::log::enabled(runner::log::Level::Trace) {
+                    continue;
+                }
+
+                let mut line_render = String::new();
+
+                for i in 0..line_length {
+                    if beams.contains_key(&i) {
+                        line_render.push('|');
+                    } else {
+                        line_render.push('.');
+                    }
+                }
+
+                runner::trace!(
+                    "{line_render} {} : {:?}",
+                    beams.values().sum::<usize>(),
+                    beams
+                );
+                continue;
+            }
+
+            let mut next_beams: HashMap<usize, usize> = HashMap::new();
+            for (i, c) in line.chars().enumerate() {
+                if c == 'S' {
+                    next_beams.insert(i, 1);
+                    continue;
+                }
+
+                if c == '^' && beams.contains_key(&i) {
+                    if i != 0 {
+                        *next_beams.entry(i - 1).or_insert(0) += beams[&i];
+                    }
+
+                    if i != line_length {
+                        *next_beams.entry(i + 1).or_insert(0) += beams[&i];
+                    }
+
+                    continue;
+                }
+
+                if beams.contains_key(&i) {
+                    *next_beams.entry(i).or_insert(0) += beams[&i];
+                }
+            }
+            beams = next_beams;
+        }
+
+        Ok(beams.values().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day07::parse("...S...\n.......\n...^.|.\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 6));
+    }
+}
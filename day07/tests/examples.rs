@@ -0,0 +1 @@
+runner::example_tests!(day07::Day07);
@@ -0,0 +1,162 @@
+/// One circuit as reported by [`Circuits::largest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Circuit {
+    /// The lowest box id in the circuit.
+    pub first: usize,
+    /// Number of boxes in the circuit.
+    pub size: usize,
+}
+
+/// Which junction boxes are wired into the same circuit: a disjoint-set
+/// forest over box ids with path compression and union by size, so joining
+/// and looking up circuits is close to constant time.
+#[derive(Debug, Clone)]
+pub struct Circuits {
+    parent: Vec<usize>,
+    /// Number of boxes in the circuit, only kept up to date for roots.
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl Circuits {
+    /// `boxes` junction boxes, each in a circuit of its own.
+    pub fn new(boxes: usize) -> Circuits {
+        Circuits {
+            parent: (0..boxes).collect(),
+            size: vec![1; boxes],
+            count: boxes,
+        }
+    }
+
+    /// The box representing the circuit `id` is in.
+    pub fn find(&mut self, id: usize) -> usize {
+        let mut root = id;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Point everything on the way straight at the root
+        let mut current = id;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+
+        root
+    }
+
+    /// Wires `a` and `b` together, returning whether that joined two
+    /// circuits rather than closing a loop in one.
+    pub fn connect(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of boxes in the circuit `id` is in.
+    pub fn size_of(&mut self, id: usize) -> usize {
+        let root = self.find(id);
+        self.size[root]
+    }
+
+    /// Number of separate circuits, counting unconnected boxes.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The size of every circuit, unconnected boxes included, in no
+    /// particular order.
+    pub fn sizes(&self) -> Vec<usize> {
+        (0..self.parent.len())
+            .filter(|&id| self.parent[id] == id)
+            .map(|root| self.size[root])
+            .collect()
+    }
+
+    /// The `k` circuits with the most boxes, largest first, counting
+    /// unconnected boxes as circuits of one. Circuits of the same size are
+    /// ordered by their lowest box id, so a tie at the cut-off always keeps
+    /// the same ones. Fewer than `k` circuits means all of them.
+    pub fn largest(&mut self, k: usize) -> Vec<Circuit> {
+        let mut circuits: Vec<Circuit> = Vec::with_capacity(self.count);
+        let mut seen = vec![false; self.parent.len()];
+
+        // Going through ids in order meets every circuit at its lowest id
+        for id in 0..self.parent.len() {
+            let root = self.find(id);
+            if !std::mem::replace(&mut seen[root], true) {
+                circuits.push(Circuit {
+                    first: id,
+                    size: self.size[root],
+                });
+            }
+        }
+
+        circuits.sort_by(|a, b| b.size.cmp(&a.size).then(a.first.cmp(&b.first)));
+        circuits.truncate(k);
+        circuits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connect() {
+        let mut circuits = Circuits::new(5);
+
+        assert!(circuits.connect(0, 1));
+        assert!(circuits.connect(3, 1));
+        assert!(!circuits.connect(0, 3));
+
+        assert!(circuits.connected(0, 3));
+        assert!(!circuits.connected(0, 4));
+        assert_eq!(circuits.size_of(1), 3);
+        assert_eq!(circuits.count(), 3);
+
+        let mut sizes = circuits.sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 1, 3]);
+    }
+
+    #[test]
+    fn test_largest() {
+        let mut circuits = Circuits::new(7);
+        circuits.connect(5, 6);
+        circuits.connect(1, 2);
+        circuits.connect(2, 3);
+
+        assert_eq!(
+            circuits.largest(3),
+            vec![
+                Circuit { first: 1, size: 3 },
+                Circuit { first: 5, size: 2 },
+                Circuit { first: 0, size: 1 },
+            ]
+        );
+        assert_eq!(circuits.largest(10).len(), 4);
+        assert!(circuits.largest(0).is_empty());
+    }
+
+    #[test]
+    fn test_find_compresses_paths() {
+        let mut circuits = Circuits::new(4);
+        circuits.parent = vec![0, 0, 1, 2];
+
+        assert_eq!(circuits.find(3), 0);
+        assert_eq!(circuits.parent, vec![0, 0, 0, 0]);
+    }
+}
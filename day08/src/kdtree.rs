@@ -0,0 +1,322 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub type Point = [i64; 3];
+
+/// How many neighbours a [`Pairs`] cursor asks for the first time; it
+/// doubles that whenever a point runs out.
+const BATCH: usize = 8;
+
+/// A k-d tree over points in 3D, answering nearest neighbour and radius
+/// queries without looking at every point. Distances are squared so they
+/// stay exact.
+#[derive(Debug, Clone)]
+pub struct KdTree {
+    points: Vec<Point>,
+    /// Point ids laid out as an implicit tree: the middle of every slice is
+    /// the node splitting it, with the lower half to its left and the upper
+    /// half to its right, cycling through the axes by depth.
+    nodes: Vec<usize>,
+}
+
+/// A point found by a query, ordered by distance and then id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Neighbour {
+    pub distance: i64,
+    pub id: usize,
+}
+
+/// Two points, `a < b`, and the squared distance between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair {
+    pub a: usize,
+    pub b: usize,
+    pub distance: i64,
+}
+
+impl KdTree {
+    /// Indexes `points`, which are referred to by their position from then on.
+    pub fn new(points: Vec<Point>) -> KdTree {
+        let mut nodes: Vec<usize> = (0..points.len()).collect();
+        build(&points, &mut nodes, 0);
+        KdTree { points, nodes }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn point(&self, id: usize) -> Point {
+        self.points[id]
+    }
+
+    /// The `k` points closest to `point`, closest first. A point of the tree
+    /// finds itself at distance 0.
+    pub fn nearest(&self, point: Point, k: usize) -> Vec<Neighbour> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut search = Nearest {
+            k,
+            found: BinaryHeap::with_capacity(k),
+        };
+        self.search(&self.nodes, 0, point, &mut search);
+        search.found.into_sorted_vec()
+    }
+
+    /// Every point within `radius_squared` of `point`, closest first.
+    pub fn within(&self, point: Point, radius_squared: i64) -> Vec<Neighbour> {
+        let mut search = Within {
+            radius_squared,
+            found: Vec::new(),
+        };
+        self.search(&self.nodes, 0, point, &mut search);
+        search.found.sort_unstable();
+        search.found
+    }
+
+    /// Every pair of points, closest first and ties ordered by ids. Pairs are
+    /// found as they're asked for, so taking the closest few is cheap.
+    pub fn pairs(&self) -> Pairs<'_> {
+        let mut pairs = Pairs {
+            tree: self,
+            cursors: vec![Cursor::default(); self.len()],
+            queue: BinaryHeap::with_capacity(self.len()),
+        };
+        for id in 0..self.len() {
+            pairs.advance(id);
+        }
+        pairs
+    }
+
+    fn search(&self, nodes: &[usize], depth: usize, point: Point, search: &mut impl Search) {
+        if nodes.is_empty() {
+            return;
+        }
+        let mid = nodes.len() / 2;
+        let id = nodes[mid];
+        search.offer(Neighbour {
+            distance: squared_distance(self.points[id], point),
+            id,
+        });
+
+        let axis = depth % 3;
+        let offset = point[axis] - self.points[id][axis];
+        let (near, far) = if offset < 0 {
+            (&nodes[..mid], &nodes[mid + 1..])
+        } else {
+            (&nodes[mid + 1..], &nodes[..mid])
+        };
+
+        self.search(near, depth + 1, point, search);
+        // Everything past the splitting plane is at least this far away
+        if offset * offset <= search.limit() {
+            self.search(far, depth + 1, point, search);
+        }
+    }
+}
+
+fn build(points: &[Point], nodes: &mut [usize], depth: usize) {
+    if nodes.len() <= 1 {
+        return;
+    }
+    let axis = depth % 3;
+    let mid = nodes.len() / 2;
+    nodes.select_nth_unstable_by_key(mid, |&id| points[id][axis]);
+
+    let (lower, upper) = nodes.split_at_mut(mid);
+    build(points, lower, depth + 1);
+    build(points, &mut upper[1..], depth + 1);
+}
+
+pub fn squared_distance(a: Point, b: Point) -> i64 {
+    a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum()
+}
+
+trait Search {
+    /// How far away a point can be and still be of interest.
+    fn limit(&self) -> i64;
+    fn offer(&mut self, neighbour: Neighbour);
+}
+
+/// The closest `k` so far, furthest on top.
+struct Nearest {
+    k: usize,
+    found: BinaryHeap<Neighbour>,
+}
+
+impl Search for Nearest {
+    fn limit(&self) -> i64 {
+        match self.found.peek() {
+            Some(furthest) if self.found.len() == self.k => furthest.distance,
+            _ => i64::MAX,
+        }
+    }
+
+    fn offer(&mut self, neighbour: Neighbour) {
+        if self.found.len() < self.k {
+            self.found.push(neighbour);
+        } else if self
+            .found
+            .peek()
+            .is_some_and(|&furthest| neighbour < furthest)
+        {
+            self.found.pop();
+            self.found.push(neighbour);
+        }
+    }
+}
+
+struct Within {
+    radius_squared: i64,
+    found: Vec<Neighbour>,
+}
+
+impl Search for Within {
+    fn limit(&self) -> i64 {
+        self.radius_squared
+    }
+
+    fn offer(&mut self, neighbour: Neighbour) {
+        if neighbour.distance <= self.radius_squared {
+            self.found.push(neighbour);
+        }
+    }
+}
+
+/// Iterator returned by [`KdTree::pairs`]. Each point walks through its own
+/// neighbours, closest first, and a queue merges those walks. A pair comes up
+/// from both ends and is only reported from its lower id.
+pub struct Pairs<'a> {
+    tree: &'a KdTree,
+    cursors: Vec<Cursor>,
+    queue: BinaryHeap<Reverse<(i64, usize, usize)>>,
+}
+
+/// How far a point has got through its neighbours.
+#[derive(Debug, Clone, Default)]
+struct Cursor {
+    /// How many neighbours the last query asked for.
+    fetched: usize,
+    /// How many neighbours have been queued.
+    seen: usize,
+    /// Fetched neighbours not queued yet, closest last.
+    pending: Vec<Neighbour>,
+}
+
+impl Pairs<'_> {
+    /// Queues the next neighbour of `id`, asking the tree for more of them
+    /// when it has run out.
+    fn advance(&mut self, id: usize) {
+        let cursor = &mut self.cursors[id];
+        if cursor.pending.is_empty() && cursor.fetched < self.tree.len() {
+            // Asking for more returns the ones we had first, as the order is total
+            cursor.fetched = (cursor.fetched * 2).max(BATCH).min(self.tree.len());
+            cursor.pending = self.tree.nearest(self.tree.point(id), cursor.fetched);
+            cursor.pending.drain(..cursor.seen);
+            cursor.pending.reverse();
+        }
+
+        if let Some(neighbour) = cursor.pending.pop() {
+            cursor.seen += 1;
+            self.queue
+                .push(Reverse((neighbour.distance, id, neighbour.id)));
+        }
+    }
+}
+
+impl Iterator for Pairs<'_> {
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Pair> {
+        while let Some(Reverse((distance, a, b))) = self.queue.pop() {
+            self.advance(a);
+            if a < b {
+                return Some(Pair { a, b, distance });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points from a small linear congruential generator, with a few
+    /// duplicates and ties thrown in.
+    fn points(n: usize) -> Vec<Point> {
+        let mut state: u64 = 42;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((state >> 33) % 50) as i64 - 25
+        };
+        (0..n).map(|_| [next(), next(), next()]).collect()
+    }
+
+    fn brute_force(points: &[Point], point: Point) -> Vec<Neighbour> {
+        let mut all: Vec<Neighbour> = points
+            .iter()
+            .enumerate()
+            .map(|(id, &p)| Neighbour {
+                distance: squared_distance(p, point),
+                id,
+            })
+            .collect();
+        all.sort_unstable();
+        all
+    }
+
+    #[test]
+    fn test_nearest() {
+        let points = points(200);
+        let tree = KdTree::new(points.clone());
+
+        for query in [[0, 0, 0], [30, -30, 5], points[17]] {
+            let expected = brute_force(&points, query);
+            for k in [0, 1, 5, 200, 300] {
+                assert_eq!(tree.nearest(query, k), expected[..k.min(200)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_within() {
+        let points = points(200);
+        let tree = KdTree::new(points.clone());
+
+        for radius_squared in [-1, 0, 50, 400, 10_000] {
+            let expected: Vec<Neighbour> = brute_force(&points, points[3])
+                .into_iter()
+                .filter(|n| n.distance <= radius_squared)
+                .collect();
+            assert_eq!(tree.within(points[3], radius_squared), expected);
+        }
+    }
+
+    #[test]
+    fn test_pairs() {
+        let mut points = points(120);
+        points.push(points[5]);
+        let tree = KdTree::new(points.clone());
+
+        let mut expected = Vec::new();
+        for a in 0..points.len() {
+            for b in a + 1..points.len() {
+                expected.push((squared_distance(points[a], points[b]), a, b));
+            }
+        }
+        expected.sort_unstable();
+
+        let pairs: Vec<(i64, usize, usize)> = tree
+            .pairs()
+            .map(|pair| (pair.distance, pair.a, pair.b))
+            .collect();
+        assert_eq!(pairs, expected);
+        assert_eq!(KdTree::new(vec![[1, 2, 3]]).pairs().next(), None);
+    }
+}
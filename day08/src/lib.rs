@@ -0,0 +1,218 @@
+pub mod circuits;
+pub mod kdtree;
+
+use anyhow::{bail, Result};
+use circuits::Circuits;
+use kdtree::{KdTree, Point};
+use runner::{lines, Param, Params, ParseError, Solution};
+
+#[derive(Debug)]
+struct Coordinates {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Coordinates {
+    fn point(&self) -> Point {
+        [self.x, self.y, self.z]
+    }
+}
+
+#[derive(Debug)]
+pub struct JunctionBox {
+    coordinates: Coordinates,
+}
+
+#[derive(Debug, Clone)]
+struct Connection {
+    source: u32,
+    target: u32,
+    /// Squared straight-line distance, which orders pairs the same way as
+    /// the distance itself while staying exact.
+    distance: i64,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "connections",
+            real: "1000",
+            example: "10",
+        },
+        Param {
+            name: "circuits",
+            real: "3",
+            example: "3",
+        },
+    ];
+
+    type Input = Vec<JunctionBox>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut junction_boxes: Vec<JunctionBox> = Vec::new();
+
+        for line in lines(input) {
+            let raw_coords: Vec<&str> = line.text.split(',').collect();
+            let [x, y, z] = raw_coords[..] else {
+                return Err(line.error(line.text, "expected X,Y,Z coordinates"));
+            };
+            let new_junction_box = JunctionBox {
+                coordinates: Coordinates {
+                    x: line.parse(x, "X coordinate")?,
+                    y: line.parse(y, "Y coordinate")?,
+                    z: line.parse(z, "Z coordinate")?,
+                },
+            };
+
+            runner::trace!("{:?}", new_junction_box);
+            junction_boxes.push(new_junction_box);
+        }
+
+        Ok(junction_boxes)
+    }
+
+    fn part1(junction_boxes: &Self::Input, params: &Params) -> Result<usize> {
+        connect_closest(
+            junction_boxes,
+            params.get("connections")?,
+            params.get("circuits")?,
+        )
+    }
+
+    fn part2(junction_boxes: &Self::Input, _params: &Params) -> Result<i64> {
+        connect_all(junction_boxes)
+    }
+}
+
+/// Pairs of junction boxes, closest first, found as they're needed rather
+/// than all up front.
+fn connections(tree: &KdTree) -> impl Iterator<Item = Connection> + '_ {
+    tree.pairs().map(|pair| Connection {
+        source: pair.a as u32,
+        target: pair.b as u32,
+        distance: pair.distance,
+    })
+}
+
+/// Connects the `connections_to_make` closest pairs of junction boxes and
+/// multiplies the sizes of the `circuits_to_multiply` largest circuits.
+fn connect_closest(
+    junction_boxes: &[JunctionBox],
+    connections_to_make: usize,
+    circuits_to_multiply: usize,
+) -> Result<usize> {
+    let tree = index(junction_boxes);
+    let mut circuits = Circuits::new(junction_boxes.len());
+
+    for c in connections(&tree).take(connections_to_make) {
+        runner::trace!("{:?}", c);
+        circuits.connect(c.source as usize, c.target as usize);
+    }
+
+    let largest = circuits.largest(circuits_to_multiply);
+    runner::debug!("Largest circuits: {:?}", largest);
+    if largest.len() < circuits_to_multiply {
+        bail!(
+            "Only {} circuits after {connections_to_make} connections, need {circuits_to_multiply}",
+            largest.len()
+        );
+    }
+
+    Ok(largest.iter().map(|circuit| circuit.size).product())
+}
+
+/// Connects the closest pairs until every junction box is in one circuit
+/// and multiplies the X coordinates of the last pair connected. That pair
+/// may join two circuits or bring in the last unconnected box, either way
+/// it's the one that drops the circuit count to one.
+fn connect_all(junction_boxes: &[JunctionBox]) -> Result<i64> {
+    if junction_boxes.len() < 2 {
+        bail!("Need at least two junction boxes to connect");
+    }
+    let tree = index(junction_boxes);
+    let mut circuits = Circuits::new(junction_boxes.len());
+
+    for c in connections(&tree) {
+        runner::trace!("{:?}", c);
+
+        if circuits.connect(c.source as usize, c.target as usize) && circuits.count() == 1 {
+            let jb_source = &junction_boxes[c.source as usize];
+            let jb_target = &junction_boxes[c.target as usize];
+            runner::debug!(
+                "Last connection: {:?} to {:?}, squared distance {}",
+                jb_source,
+                jb_target,
+                c.distance
+            );
+
+            return Ok(jb_source.coordinates.x * jb_target.coordinates.x);
+        }
+    }
+
+    unreachable!("connecting every pair leaves a single circuit")
+}
+
+/// A k-d tree over the junction boxes, with boxes known by their position
+/// in the input.
+fn index(junction_boxes: &[JunctionBox]) -> KdTree {
+    KdTree::new(
+        junction_boxes
+            .iter()
+            .map(|jb| jb.coordinates.point())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_connection_can_bring_in_a_single_box() {
+        let junction_boxes = Day08::parse("0,0,0\n2,0,0\n20,0,0\n3,0,0\n").unwrap();
+
+        assert_eq!(
+            Day08::part2(&junction_boxes, &Day08::example_params()).unwrap(),
+            60
+        );
+
+        let single_box = Day08::parse("0,0,0\n").unwrap();
+        assert!(Day08::part2(&single_box, &Day08::example_params()).is_err());
+    }
+
+    #[test]
+    fn test_connections() {
+        let junction_boxes = Day08::parse("0,0,0\n10,0,0\n0,3,4\n1,1,1\n").unwrap();
+        let closest: Vec<(u32, u32, i64)> = connections(&index(&junction_boxes))
+            .take(3)
+            .map(|c| (c.source, c.target, c.distance))
+            .collect();
+
+        assert_eq!(closest, vec![(0, 3, 3), (2, 3, 14), (0, 2, 25)]);
+        assert_eq!(connections(&index(&junction_boxes)).count(), 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day08::parse("162,817,812\n57,6x8,52\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            concat!(
+                "invalid Y coordinate \"6x8\" at line 2, column 4\n",
+                "  |\n",
+                "2 | 57,6x8,52\n",
+                "  |    ^^^",
+            )
+        );
+
+        let err = Day08::parse("162,817\n").unwrap_err();
+        assert_eq!(err.message, "expected X,Y,Z coordinates");
+    }
+}
@@ -0,0 +1 @@
+runner::example_tests!(day08::Day08);
@@ -0,0 +1,216 @@
+use crate::table;
+use anyhow::{Context, Result};
+use runner::bench::Stage;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Benchmark results are machine specific, so they stay out of git.
+pub const HISTORY_FILE: &str = "bench_history.toml";
+
+/// Summary statistics of a set of timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+    /// Runs outside Tukey's fences, 1.5 interquartile ranges beyond the
+    /// first and third quartiles.
+    pub outliers: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarize");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (nanos.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        let q1 = quantile(&nanos, 0.25);
+        let q3 = quantile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        Stats {
+            runs: sorted.len(),
+            mean: Duration::from_nanos(mean as u64),
+            median: Duration::from_nanos(quantile(&nanos, 0.5) as u64),
+            min: sorted[0],
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+            outliers: nanos.iter().filter(|&&n| n < low || n > high).count(),
+        }
+    }
+}
+
+/// Linearly interpolated quantile of sorted values.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let (below, above) = (position.floor() as usize, position.ceil() as usize);
+    sorted[below] + (sorted[above] - sorted[below]) * (position - below as f64)
+}
+
+/// One benchmarked stage as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u32,
+    pub stage: String,
+    pub recorded_at: String,
+    pub runs: usize,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Entry {
+    pub fn new(day: u32, stage: Stage, recorded_at: &str, stats: &Stats) -> Entry {
+        Entry {
+            day,
+            stage: stage.name().to_string(),
+            recorded_at: recorded_at.to_string(),
+            runs: stats.runs,
+            mean_ns: stats.mean.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            min_ns: stats.min.as_nanos() as u64,
+            stddev_ns: stats.stddev.as_nanos() as u64,
+        }
+    }
+}
+
+/// Every benchmark run so far, oldest first.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "entry")]
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<History> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+
+        let content = fs::read_to_string(path).context("Failed to read benchmark history")?;
+        toml::from_str(&content).context("Failed to parse benchmark history")
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = toml::to_string(self).context("Failed to serialize benchmark history")?;
+        fs::write(path, content).context("Failed to write benchmark history")
+    }
+
+    /// The most recent earlier result for the same day and stage.
+    pub fn baseline(&self, day: u32, stage: Stage) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.day == day && entry.stage == stage.name())
+    }
+}
+
+/// Relative change of the median against the baseline, e.g. `-4.2%`.
+pub fn change(stats: &Stats, baseline: Option<&Entry>) -> String {
+    let Some(baseline) = baseline.filter(|baseline| baseline.median_ns > 0) else {
+        return "new".to_string();
+    };
+
+    let median = stats.median.as_nanos() as f64;
+    let percent = (median - baseline.median_ns as f64) / baseline.median_ns as f64 * 100.0;
+    format!("{percent:+.1}%")
+}
+
+/// One benchmarked stage, ready to display.
+pub struct Measurement {
+    pub day: u32,
+    pub stage: Stage,
+    pub stats: Stats,
+    pub change: String,
+}
+
+const HEADERS: [&str; 9] = [
+    "Day", "Stage", "Mean", "Median", "Min", "Std dev", "Runs", "Outliers", "Change",
+];
+
+const RIGHT_ALIGNED: [bool; 9] = [true, false, true, true, true, true, true, true, true];
+
+pub fn render(results: &[Measurement]) -> String {
+    let rows: Vec<[String; 9]> = results
+        .iter()
+        .map(|result| {
+            let stats = &result.stats;
+            [
+                result.day.to_string(),
+                result.stage.to_string(),
+                table::format_duration(stats.mean),
+                table::format_duration(stats.median),
+                table::format_duration(stats.min),
+                table::format_duration(stats.stddev),
+                stats.runs.to_string(),
+                stats.outliers.to_string(),
+                result.change.clone(),
+            ]
+        })
+        .collect();
+
+    table::layout(HEADERS, &rows, None, RIGHT_ALIGNED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&micros(&[12, 10, 11, 10, 13, 11, 52]));
+
+        assert_eq!(stats.runs, 7);
+        assert_eq!(stats.median, Duration::from_micros(11));
+        assert_eq!(stats.min, Duration::from_micros(10));
+        assert_eq!(stats.mean, Duration::from_micros(17));
+        assert_eq!(stats.outliers, 1);
+        assert!(stats.stddev > Duration::from_micros(14));
+    }
+
+    #[test]
+    fn test_single_sample() {
+        let stats = Stats::from_samples(&micros(&[5]));
+
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn test_change_against_baseline() {
+        let stats = Stats::from_samples(&micros(&[90]));
+        let mut history = History::default();
+        assert_eq!(change(&stats, history.baseline(1, Stage::Part1)), "new");
+
+        let old = Stats::from_samples(&micros(&[100]));
+        history
+            .entries
+            .push(Entry::new(1, Stage::Part1, "2025-12-01T00:00:00Z", &old));
+        history
+            .entries
+            .push(Entry::new(1, Stage::Part2, "2025-12-01T00:00:00Z", &stats));
+
+        assert_eq!(change(&stats, history.baseline(1, Stage::Part1)), "-10.0%");
+        assert_eq!(change(&old, history.baseline(1, Stage::Part2)), "+11.1%");
+        assert_eq!(change(&stats, history.baseline(2, Stage::Part1)), "new");
+    }
+}
@@ -0,0 +1,14 @@
+use runner::Day;
+
+/// Every implemented day, in order. The scaffold adds new days here.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day01::Day01>(),
+        Day::new::<day02::Day02>(),
+        Day::new::<day03::Day03>(),
+        Day::new::<day05::Day05>(),
+        Day::new::<day06::Day06>(),
+        Day::new::<day07::Day07>(),
+        Day::new::<day08::Day08>(),
+    ]
+}
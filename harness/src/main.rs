@@ -0,0 +1,222 @@
+mod bench;
+mod days;
+mod table;
+mod verify;
+
+use anyhow::{anyhow, Context, Result};
+use bench::{Entry, History, Measurement, Stats};
+use chrono::{SecondsFormat, Utc};
+use runner::answers::{self, Answers};
+use runner::args::take_option;
+use runner::bench::{Plan, Stage};
+use runner::Day;
+use std::env;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::panic;
+use std::process;
+use table::{Outcome, Row};
+
+const USAGE: &str = "\
+Usage: harness run [DAY | FIRST-LAST]
+       harness verify [DAY | FIRST-LAST]
+       harness bench [DAY | FIRST-LAST] [--runs N]
+       harness record <day> <part> <answer>
+
+Add -v or -vv (or set AOC_LOG=debug|trace) for the days' diagnostics.";
+
+/// Parses a day selection such as `5` or `3-7`; no selection means every day.
+fn parse_days(arg: Option<&str>) -> Result<RangeInclusive<u32>> {
+    let Some(arg) = arg else {
+        return Ok(1..=u32::MAX);
+    };
+
+    let parse = |day: &str| {
+        day.trim()
+            .parse::<u32>()
+            .with_context(|| format!("Invalid day number: {day}"))
+    };
+    let range = match arg.split_once('-') {
+        Some((first, last)) => parse(first)?..=parse(last)?,
+        None => parse(arg)?..=parse(arg)?,
+    };
+
+    if range.is_empty() {
+        return Err(anyhow!("Empty day range: {arg}"));
+    }
+    Ok(range)
+}
+
+/// Parses `--runs N`; every benchmark needs at least one sample.
+fn parse_runs(arg: &str) -> Result<usize> {
+    let runs: usize = arg
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid number of runs: {arg}"))?;
+    if runs == 0 {
+        return Err(anyhow!("--runs must be at least 1"));
+    }
+    Ok(runs)
+}
+
+fn selected_days(selection: RangeInclusive<u32>) -> Result<Vec<Day>> {
+    let days: Vec<_> = days::all()
+        .into_iter()
+        .filter(|day| selection.contains(&day.day))
+        .collect();
+    if days.is_empty() {
+        return Err(anyhow!("No implemented days in the selected range"));
+    }
+    Ok(days)
+}
+
+/// Solves a day against its input, surviving a panicking solution.
+fn run_day(day: &Day) -> Outcome {
+    match fs::read_to_string(runner::input_path(day.day)) {
+        Ok(input) => match panic::catch_unwind(|| day.solve(&input)) {
+            Ok(Ok(report)) => Outcome::Solved(report),
+            Ok(Err(err)) => Outcome::Failed(format!("{err:#}")),
+            Err(_) => Outcome::Panicked,
+        },
+        Err(_) => Outcome::MissingInput,
+    }
+}
+
+/// Runs every selected day that has an input and prints their answers and
+/// timings as a table.
+fn run(selection: RangeInclusive<u32>) -> Result<()> {
+    let rows: Vec<Row> = selected_days(selection)?
+        .iter()
+        .map(|day| Row {
+            day: day.day,
+            outcome: run_day(day),
+        })
+        .collect();
+
+    print!("{}", table::render(&rows));
+    Ok(())
+}
+
+/// Checks every selected day against its known answers, exiting with a
+/// failure if any answer changed or a day panicked.
+fn verify(selection: RangeInclusive<u32>) -> Result<()> {
+    let mut summary = verify::Summary::default();
+    for day in selected_days(selection)? {
+        let answers = Answers::load(&runner::answers_path(day.day))?;
+        let outcome = run_day(&day);
+        print!(
+            "{}",
+            verify::verify_day(day.day, &outcome, &answers, &mut summary)
+        );
+    }
+
+    println!("\n{summary}");
+    if summary.failed() {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// Benchmarks every stage of the selected days, comparing each median with
+/// the previous run's and adding the results to the history file.
+fn bench(selection: RangeInclusive<u32>, plan: &Plan) -> Result<()> {
+    let history_path = runner::workspace_root().join(bench::HISTORY_FILE);
+    let mut history = History::load(&history_path)?;
+    let recorded_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let mut measurements = Vec::new();
+    for day in selected_days(selection)? {
+        let Ok(input) = fs::read_to_string(runner::input_path(day.day)) else {
+            println!("⏭️  Day {:2}: missing input", day.day);
+            continue;
+        };
+
+        for stage in Stage::ALL {
+            eprintln!("⏱️  Day {:2} {stage}...", day.day);
+            let samples = match panic::catch_unwind(|| day.sample(&input, stage, plan)) {
+                Ok(Ok(samples)) => samples,
+                Ok(Err(err)) => {
+                    println!("💥 Day {:2} {stage}: {err:#}", day.day);
+                    break;
+                }
+                Err(_) => {
+                    println!("💥 Day {:2} {stage}: panicked", day.day);
+                    break;
+                }
+            };
+
+            let stats = Stats::from_samples(&samples);
+            measurements.push(Measurement {
+                day: day.day,
+                stage,
+                stats,
+                change: bench::change(&stats, history.baseline(day.day, stage)),
+            });
+        }
+    }
+
+    print!("{}", bench::render(&measurements));
+
+    history.entries.extend(
+        measurements
+            .iter()
+            .map(|m| Entry::new(m.day, m.stage, &recorded_at, &m.stats)),
+    );
+    history.save(&history_path)
+}
+
+/// Stores an answer known to be correct, e.g. one accepted before answers
+/// were recorded automatically.
+fn record(args: &[String]) -> Result<()> {
+    let [day, part, answer] = args else {
+        return Err(anyhow!("Usage: harness record <day> <part> <answer>"));
+    };
+    let day: u32 = day.parse().context("Invalid day number provided")?;
+    let part: u32 = part.parse().context("Invalid part number provided")?;
+
+    answers::record(&runner::answers_path(day), part, answer)?;
+    println!("📝 Recorded {answer} as the answer to Day {day} Part {part}");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    runner::log::init(runner::log::take_verbosity(&mut args))?;
+    let runs = take_option(&mut args, "--runs")?
+        .map(|runs| parse_runs(&runs))
+        .transpose()?;
+    let selection = || parse_days(args.get(1).map(String::as_str));
+
+    match args.first().map(String::as_str) {
+        Some("run") => run(selection()?),
+        Some("verify") => verify(selection()?),
+        Some("bench") => bench(selection()?, &runs.map_or_else(Plan::default, Plan::fixed)),
+        Some("record") => record(&args[1..]),
+        Some(command) => Err(anyhow!("Unknown command: {command}\n{USAGE}")),
+        None => run(parse_days(None)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days(Some("5")).unwrap(), 5..=5);
+        assert_eq!(parse_days(Some("3-7")).unwrap(), 3..=7);
+        assert!(parse_days(None).unwrap().contains(&12));
+        assert!(parse_days(Some("7-3")).is_err());
+        assert!(parse_days(Some("x")).is_err());
+    }
+
+    #[test]
+    fn test_parse_runs() {
+        assert_eq!(parse_runs("20").unwrap(), 20);
+        assert_eq!(
+            parse_runs("0").unwrap_err().to_string(),
+            "--runs must be at least 1"
+        );
+        assert!(parse_runs("many").is_err());
+    }
+}
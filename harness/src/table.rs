@@ -0,0 +1,221 @@
+use runner::{Report, Timings};
+use std::time::Duration;
+
+/// What happened when a day was run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Report),
+    MissingInput,
+    /// The solution returned an error, e.g. input it couldn't parse.
+    Failed(String),
+    Panicked,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u32,
+    pub outcome: Outcome,
+}
+
+const HEADERS: [&str; 7] = [
+    "Day", "Part 1", "Part 2", "Parse", "Part 1", "Part 2", "Total",
+];
+
+/// Answer columns are left aligned, the day and durations right aligned.
+const RIGHT_ALIGNED: [bool; 7] = [true, false, false, true, true, true, true];
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+fn timing_cells(timings: &Timings) -> [String; 4] {
+    [
+        format_duration(timings.parse),
+        format_duration(timings.part1),
+        format_duration(timings.part2),
+        format_duration(timings.total()),
+    ]
+}
+
+fn cells(row: &Row) -> [String; 7] {
+    let day = row.day.to_string();
+    match &row.outcome {
+        Outcome::Solved(report) => {
+            let [parse, part1, part2, total] = timing_cells(&report.timings);
+            [
+                day,
+                report.part1.clone(),
+                report.part2.clone(),
+                parse,
+                part1,
+                part2,
+                total,
+            ]
+        }
+        Outcome::MissingInput => [
+            day,
+            "missing input".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ],
+        Outcome::Failed(err) => [
+            day,
+            format!("error: {}", err.lines().next().unwrap_or_default()),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ],
+        Outcome::Panicked => [
+            day,
+            "panicked".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ],
+    }
+}
+
+/// Sums the timings of every solved day.
+pub fn total(rows: &[Row]) -> Timings {
+    rows.iter()
+        .filter_map(|row| match &row.outcome {
+            Outcome::Solved(report) => Some(report.timings),
+            _ => None,
+        })
+        .fold(Timings::default(), |sum, timings| Timings {
+            parse: sum.parse + timings.parse,
+            part1: sum.part1 + timings.part1,
+            part2: sum.part2 + timings.part2,
+        })
+}
+
+/// Renders the rows as an aligned table, ending with a grand total.
+pub fn render(rows: &[Row]) -> String {
+    let [parse, part1, part2, total] = timing_cells(&total(rows));
+    let total_row = [
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        parse,
+        part1,
+        part2,
+        total,
+    ];
+
+    let rows: Vec<[String; 7]> = rows.iter().map(cells).collect();
+    layout(HEADERS, &rows, Some(total_row), RIGHT_ALIGNED)
+}
+
+/// Lays out cells in aligned columns, with a rule under the header and
+/// above the footer.
+pub fn layout<const N: usize>(
+    header: [&str; N],
+    rows: &[[String; N]],
+    footer: Option<[String; N]>,
+    right_aligned: [bool; N],
+) -> String {
+    let mut lines: Vec<[String; N]> = vec![header.map(String::from)];
+    lines.extend(rows.iter().cloned());
+    lines.extend(footer.clone());
+
+    let mut widths = [0; N];
+    for line in &lines {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let separator = widths.map(|width| "-".repeat(width));
+    lines.insert(1, separator.clone());
+    if footer.is_some() {
+        lines.insert(lines.len() - 1, separator);
+    }
+
+    let mut table = String::new();
+    for line in &lines {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(widths)
+            .zip(right_aligned)
+            .map(|((cell, width), right)| {
+                if right {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved(day: u32, part1: &str, part2: &str, millis: u64) -> Row {
+        Row {
+            day,
+            outcome: Outcome::Solved(Report {
+                part1: part1.to_string(),
+                part2: part2.to_string(),
+                timings: Timings {
+                    parse: Duration::from_millis(millis),
+                    part1: Duration::from_millis(millis),
+                    part2: Duration::from_millis(millis),
+                },
+            }),
+        }
+    }
+
+    #[test]
+    fn test_total_skips_unsolved_days() {
+        let rows = vec![
+            solved(1, "1", "2", 1),
+            Row {
+                day: 2,
+                outcome: Outcome::MissingInput,
+            },
+            solved(3, "3", "4", 2),
+            Row {
+                day: 4,
+                outcome: Outcome::Failed("invalid digit".to_string()),
+            },
+        ];
+
+        assert_eq!(total(&rows).total(), Duration::from_millis(9));
+    }
+
+    #[test]
+    fn test_render() {
+        let rows = vec![
+            solved(1, "1150", "6738", 1),
+            Row {
+                day: 12,
+                outcome: Outcome::MissingInput,
+            },
+        ];
+
+        assert_eq!(
+            render(&rows),
+            concat!(
+                "  Day  Part 1         Part 2   Parse  Part 1  Part 2   Total\n",
+                "-----  -------------  ------  ------  ------  ------  ------\n",
+                "    1  1150           6738    1.00ms  1.00ms  1.00ms  3.00ms\n",
+                "   12  missing input\n",
+                "-----  -------------  ------  ------  ------  ------  ------\n",
+                "Total                         1.00ms  1.00ms  1.00ms  3.00ms\n",
+            )
+        );
+    }
+}
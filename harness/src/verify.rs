@@ -0,0 +1,161 @@
+use crate::table::Outcome;
+use runner::answers::Answers;
+use std::fmt;
+
+/// How an answer compares to the known correct one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Match,
+    Mismatch { expected: String },
+    Missing,
+}
+
+pub fn check(expected: Option<&str>, actual: &str) -> Check {
+    match expected {
+        Some(expected) if expected == runner::normalize(actual) => Check::Match,
+        Some(expected) => Check::Mismatch {
+            expected: expected.to_string(),
+        },
+        None => Check::Missing,
+    }
+}
+
+/// Tally of every checked answer.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub matched: usize,
+    pub mismatched: usize,
+    pub missing: usize,
+    pub panicked: usize,
+    pub errors: usize,
+    pub skipped: usize,
+}
+
+impl Summary {
+    pub fn failed(&self) -> bool {
+        self.mismatched > 0 || self.panicked > 0 || self.errors > 0
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} matched, {} mismatched, {} without a known answer",
+            self.matched, self.mismatched, self.missing
+        )?;
+        if self.panicked > 0 {
+            write!(f, ", {} panicked", self.panicked)?;
+        }
+        if self.errors > 0 {
+            write!(f, ", {} failed with an error", self.errors)?;
+        }
+        if self.skipped > 0 {
+            write!(f, ", {} skipped for lack of input", self.skipped)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks a day's answers against the known ones, returning a line per part
+/// and adding them to `summary`.
+pub fn verify_day(day: u32, outcome: &Outcome, answers: &Answers, summary: &mut Summary) -> String {
+    let report = match outcome {
+        Outcome::Solved(report) => report,
+        Outcome::MissingInput => {
+            summary.skipped += 1;
+            return format!("⏭️  Day {day:2}: missing input\n");
+        }
+        Outcome::Failed(err) => {
+            summary.errors += 1;
+            return format!("💥 Day {day:2}: {err}\n");
+        }
+        Outcome::Panicked => {
+            summary.panicked += 1;
+            return format!("💥 Day {day:2}: panicked\n");
+        }
+    };
+
+    let mut lines = String::new();
+    for (part, actual) in [(1, &report.part1), (2, &report.part2)] {
+        let line = match check(answers.get(part), actual) {
+            Check::Match => {
+                summary.matched += 1;
+                format!("✅ Day {day:2} Part {part}: {actual}")
+            }
+            Check::Mismatch { expected } => {
+                summary.mismatched += 1;
+                format!("❌ Day {day:2} Part {part}: got {actual}, expected {expected}")
+            }
+            Check::Missing => {
+                summary.missing += 1;
+                format!("❔ Day {day:2} Part {part}: {actual} (no known answer)")
+            }
+        };
+        lines.push_str(&line);
+        lines.push('\n');
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::{Report, Timings};
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(Some("40"), "40"), Check::Match);
+        assert_eq!(
+            check(Some("40"), "41"),
+            Check::Mismatch {
+                expected: "40".to_string()
+            }
+        );
+        assert_eq!(check(None, "40"), Check::Missing);
+    }
+
+    #[test]
+    fn test_verify_day() {
+        let outcome = Outcome::Solved(Report {
+            part1: "21".to_string(),
+            part2: "41".to_string(),
+            timings: Timings::default(),
+        });
+        let answers = Answers {
+            part1: Some("21".to_string()),
+            part2: Some("40".to_string()),
+        };
+        let mut summary = Summary::default();
+
+        assert_eq!(
+            verify_day(7, &outcome, &answers, &mut summary),
+            "✅ Day  7 Part 1: 21\n❌ Day  7 Part 2: got 41, expected 40\n"
+        );
+        verify_day(8, &outcome, &Answers::default(), &mut summary);
+        verify_day(9, &Outcome::MissingInput, &answers, &mut summary);
+        assert_eq!(
+            verify_day(
+                10,
+                &Outcome::Failed("invalid digit".to_string()),
+                &answers,
+                &mut summary
+            ),
+            "💥 Day 10: invalid digit\n"
+        );
+
+        assert_eq!(
+            summary,
+            Summary {
+                matched: 1,
+                mismatched: 1,
+                missing: 2,
+                panicked: 0,
+                errors: 1,
+                skipped: 1,
+            }
+        );
+        assert!(summary.failed());
+    }
+}
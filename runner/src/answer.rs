@@ -0,0 +1,65 @@
+use num_bigint::{BigInt, BigUint};
+
+/// Something a part can return: rendered the same way whether it's printed,
+/// checked against `answers.toml` or submitted.
+pub trait Answer {
+    /// The answer as it would be typed into the puzzle page.
+    fn render(&self) -> String;
+}
+
+macro_rules! integer_answers {
+    ($($ty:ty),*) => {
+        $(
+            impl Answer for $ty {
+                fn render(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+integer_answers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, BigInt, BigUint);
+
+impl Answer for String {
+    fn render(&self) -> String {
+        normalize(self)
+    }
+}
+
+impl Answer for &str {
+    fn render(&self) -> String {
+        normalize(self)
+    }
+}
+
+/// The canonical form of an answer typed in or stored, so it compares equal
+/// to a rendered one: surrounding whitespace, such as the newline after a
+/// drawn grid or a pasted answer, doesn't count.
+pub fn normalize(answer: &str) -> String {
+    answer.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        assert_eq!((-12i64).render(), "-12");
+        assert_eq!(
+            u128::MAX.render(),
+            "340282366920938463463374607431768211455"
+        );
+        assert_eq!(
+            (BigUint::from(u128::MAX) * 10u32).render(),
+            "3402823669209384634633746074317682114550"
+        );
+        assert_eq!("EFEHRLPH\n".to_string().render(), "EFEHRLPH");
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("  42\n"), "42");
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// The known correct answers for a day, kept out of git in
+/// `dayXX/answers.toml` next to the puzzle input.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    /// Reads the answers at `path`; a missing file means none are known yet.
+    pub fn load(path: &Path) -> Result<Answers> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = toml::to_string(self).context("Failed to serialize answers")?;
+        fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u32, answer: &str) -> Result<()> {
+        let slot = match part {
+            1 => &mut self.part1,
+            2 => &mut self.part2,
+            _ => return Err(anyhow!("Part must be 1 or 2")),
+        };
+        *slot = Some(crate::normalize(answer));
+        Ok(())
+    }
+}
+
+/// Stores `answer` as the known answer to `part`, keeping the other part's.
+pub fn record(path: &Path, part: u32, answer: &str) -> Result<()> {
+    let mut answers = Answers::load(path)?;
+    answers.set(part, answer)?;
+    answers.save(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    #[test]
+    fn test_record() {
+        let dir = temp_dir("answers");
+        let path = dir.join("answers.toml");
+
+        assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+
+        record(&path, 2, "6738\n").unwrap();
+        record(&path, 1, "1150").unwrap();
+        record(&path, 2, "6739").unwrap();
+
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.get(1), Some("1150"));
+        assert_eq!(answers.get(2), Some("6739"));
+        assert!(record(&path, 3, "0").is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Result};
+
+/// Removes `name <value>` or `name=<value>` from `args`, returning the value.
+pub fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>> {
+    let prefix = format!("{name}=");
+    let Some(i) = args
+        .iter()
+        .position(|arg| arg == name || arg.starts_with(&prefix))
+    else {
+        return Ok(None);
+    };
+
+    let arg = args.remove(i);
+    if let Some(value) = arg.strip_prefix(&prefix) {
+        return Ok(Some(value.to_string()));
+    }
+
+    if i < args.len() {
+        Ok(Some(args.remove(i)))
+    } else {
+        Err(anyhow!("{name} requires a value"))
+    }
+}
+
+/// Removes every occurrence of the switch `name` from `args`, returning
+/// whether it was present.
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let before = args.len();
+    args.retain(|arg| arg != name);
+    args.len() != before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::args;
+
+    #[test]
+    fn test_take_option() {
+        let mut list = args(&["--year", "2024", "5", "--url=http://localhost"]);
+
+        assert_eq!(
+            take_option(&mut list, "--year").unwrap(),
+            Some("2024".to_string())
+        );
+        assert_eq!(
+            take_option(&mut list, "--url").unwrap(),
+            Some("http://localhost".to_string())
+        );
+        assert_eq!(take_option(&mut list, "--year").unwrap(), None);
+        assert_eq!(list, args(&["5"]));
+    }
+
+    #[test]
+    fn test_take_flag() {
+        let mut list = args(&["--offline", "5"]);
+
+        assert!(take_flag(&mut list, "--offline"));
+        assert!(!take_flag(&mut list, "--offline"));
+        assert_eq!(list, args(&["5"]));
+    }
+
+    #[test]
+    fn test_take_option_requires_value() {
+        let mut list = args(&["5", "--year"]);
+        assert!(take_option(&mut list, "--year").is_err());
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Params, Solution};
+use anyhow::Result;
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// A separately timed piece of a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// How long to warm up and how many runs to measure. The number of runs
+/// adapts to the cost of a run so slow days don't take forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plan {
+    pub warmup: Duration,
+    pub measure: Duration,
+    pub min_runs: usize,
+    pub max_runs: usize,
+}
+
+impl Default for Plan {
+    fn default() -> Plan {
+        Plan {
+            warmup: Duration::from_millis(500),
+            measure: Duration::from_secs(3),
+            min_runs: 10,
+            max_runs: 1000,
+        }
+    }
+}
+
+impl Plan {
+    /// Always exactly `runs` runs.
+    pub fn fixed(runs: usize) -> Plan {
+        Plan {
+            min_runs: runs,
+            max_runs: runs,
+            ..Plan::default()
+        }
+    }
+
+    /// Number of runs that fit the measuring budget when one takes
+    /// `estimate`.
+    pub fn runs(&self, estimate: Duration) -> usize {
+        let fitting = self.measure.as_nanos() / estimate.as_nanos().max(1);
+        (fitting as usize).clamp(self.min_runs, self.max_runs)
+    }
+}
+
+/// Times one stage of a solution over many runs, after warming up. The
+/// parts are timed against an input parsed once up front, with the real
+/// input's parameters, and only once a first run has succeeded.
+pub fn sample<S: Solution>(input: &str, stage: Stage, plan: &Plan) -> Result<Vec<Duration>> {
+    let params = Params::real(S::PARAMS);
+    let data = S::parse(input)?;
+    Ok(match stage {
+        Stage::Parse => time(plan, || {
+            let _ = black_box(S::parse(black_box(input)));
+        }),
+        Stage::Part1 => {
+            S::part1(&data, &params)?;
+            time(plan, || {
+                let _ = black_box(S::part1(black_box(&data), &params));
+            })
+        }
+        Stage::Part2 => {
+            S::part2(&data, &params)?;
+            time(plan, || {
+                let _ = black_box(S::part2(black_box(&data), &params));
+            })
+        }
+    })
+}
+
+fn time(plan: &Plan, mut run: impl FnMut()) -> Vec<Duration> {
+    // Warm up caches and branch predictors, which also tells how long a run
+    // takes
+    let start = Instant::now();
+    let mut warmups = 0;
+    while warmups == 0 || start.elapsed() < plan.warmup {
+        run();
+        warmups += 1;
+    }
+    let estimate = start.elapsed() / warmups;
+
+    (0..plan.runs(estimate))
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runs_fit_the_budget() {
+        let plan = Plan {
+            measure: Duration::from_secs(1),
+            ..Plan::default()
+        };
+
+        assert_eq!(plan.runs(Duration::from_millis(10)), 100);
+        assert_eq!(plan.runs(Duration::from_secs(2)), 10);
+        assert_eq!(plan.runs(Duration::ZERO), 1000);
+        assert_eq!(Plan::fixed(5).runs(Duration::from_nanos(1)), 5);
+    }
+}
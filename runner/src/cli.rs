@@ -0,0 +1,184 @@
+use crate::args::{take_flag, take_option};
+use crate::InputSource;
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::time::Duration;
+
+/// Which parts to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    One,
+    Two,
+    Both,
+}
+
+impl Parts {
+    pub fn selected(&self) -> &'static [u32] {
+        match self {
+            Parts::One => &[1],
+            Parts::Two => &[2],
+            Parts::Both => &[1, 2],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One JSON object per line and part, for scripts.
+    Json,
+}
+
+/// The command line every day binary accepts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub input: InputSource,
+    pub parts: Parts,
+    /// Show how long parsing and each part took.
+    pub time: bool,
+    /// Print nothing but the answers.
+    pub quiet: bool,
+    pub format: Format,
+    /// `name=value` overrides from `--param`.
+    pub params: Vec<String>,
+    /// How many `-v`s were given, see [`crate::log`].
+    pub verbosity: u8,
+}
+
+/// One answer as printed with `--format json`.
+#[derive(Debug, Serialize)]
+struct JsonAnswer<'a> {
+    day: u32,
+    part: u32,
+    answer: &'a str,
+    elapsed_ns: u64,
+}
+
+impl Options {
+    /// Takes `--part 1|2|both`, `--time`, `--quiet`, `--format text|json`,
+    /// any number of `--param name=value`, `-v`/`-vv` and the input source
+    /// out of `args`.
+    pub fn from_args(args: &mut Vec<String>) -> Result<Options> {
+        let mut params = Vec::new();
+        while let Some(param) = take_option(args, "--param")? {
+            params.push(param);
+        }
+
+        let parts = match take_option(args, "--part")?.as_deref() {
+            None | Some("both") => Parts::Both,
+            Some("1") => Parts::One,
+            Some("2") => Parts::Two,
+            Some(other) => return Err(anyhow!("--part must be 1, 2 or both, not {other}")),
+        };
+        let format = match take_option(args, "--format")?.as_deref() {
+            None | Some("text") => Format::Text,
+            Some("json") => Format::Json,
+            Some(other) => return Err(anyhow!("--format must be text or json, not {other}")),
+        };
+
+        Ok(Options {
+            input: InputSource::from_args(args)?,
+            parts,
+            time: take_flag(args, "--time"),
+            quiet: take_flag(args, "--quiet") | take_flag(args, "-q"),
+            format,
+            params,
+            verbosity: crate::log::take_verbosity(args),
+        })
+    }
+
+    /// The line printed for a parse step taking `elapsed`, if any.
+    pub fn render_parse(&self, elapsed: Duration) -> Option<String> {
+        (self.time && !self.quiet && self.format == Format::Text)
+            .then(|| format!("Parse: {elapsed:.2?}"))
+    }
+
+    /// The line printed for one part's answer.
+    pub fn render_answer(&self, day: u32, part: u32, answer: &str, elapsed: Duration) -> String {
+        match self.format {
+            Format::Json => serde_json::to_string(&JsonAnswer {
+                day,
+                part,
+                answer,
+                elapsed_ns: elapsed.as_nanos() as u64,
+            })
+            .expect("answers serialize to JSON"),
+            Format::Text if self.quiet => answer.to_string(),
+            Format::Text if self.time => format!("Part {part}: {answer} ({elapsed:.2?})"),
+            Format::Text => format!("Part {part}: {answer}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::args;
+
+    fn options(list: &[&str]) -> Result<Options> {
+        Options::from_args(&mut args(list))
+    }
+
+    #[test]
+    fn test_from_args() {
+        let defaults = options(&[]).unwrap();
+        assert_eq!(defaults.parts, Parts::Both);
+        assert_eq!(defaults.format, Format::Text);
+        assert!(!defaults.time && !defaults.quiet);
+
+        let options = options(&[
+            "--part",
+            "2",
+            "--format=json",
+            "--param",
+            "size=7",
+            "--time",
+            "-q",
+            "--param=steps=12",
+            "-vv",
+        ])
+        .unwrap();
+        assert_eq!(options.parts.selected(), &[2]);
+        assert_eq!(options.format, Format::Json);
+        assert!(options.time && options.quiet);
+        assert_eq!(options.params, vec!["size=7", "steps=12"]);
+        assert_eq!(options.verbosity, 2);
+    }
+
+    #[test]
+    fn test_rejects_bad_values() {
+        assert!(options(&["--part", "3"]).is_err());
+        assert!(options(&["--format", "yaml"]).is_err());
+    }
+
+    #[test]
+    fn test_render_answer() {
+        let elapsed = Duration::from_micros(1500);
+        let render = |list: &[&str]| options(list).unwrap().render_answer(8, 1, "40", elapsed);
+
+        assert_eq!(render(&[]), "Part 1: 40");
+        assert_eq!(render(&["--time"]), "Part 1: 40 (1.50ms)");
+        assert_eq!(render(&["--quiet", "--time"]), "40");
+        assert_eq!(
+            render(&["--format", "json"]),
+            r#"{"day":8,"part":1,"answer":"40","elapsed_ns":1500000}"#
+        );
+    }
+
+    #[test]
+    fn test_render_parse() {
+        let elapsed = Duration::from_micros(20);
+
+        assert_eq!(options(&[]).unwrap().render_parse(elapsed), None);
+        assert_eq!(
+            options(&["--time"]).unwrap().render_parse(elapsed),
+            Some("Parse: 20.00µs".to_string())
+        );
+        assert_eq!(
+            options(&["--time", "--quiet"])
+                .unwrap()
+                .render_parse(elapsed),
+            None
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! The examples from the puzzle text, kept in `dayXX/examples/` as input
+//! files plus a `manifest.toml` with the answers each should produce:
+//!
+//! ```toml
+//! [[example]]
+//! name = "example1"
+//! input = "example1.txt"
+//! part1 = "40"
+//! part2 = "25272"
+//!
+//! [example.params]
+//! connections = "10"
+//! ```
+//!
+//! Every answer listed becomes a test through [`example_tests!`](crate::example_tests),
+//! so an edge case is added by dropping in a file and a manifest entry.
+
+use crate::{day_dir, normalize, Answer, Params, Solution};
+use anyhow::{anyhow, Context, Result};
+use libtest_mimic::{Arguments, Trial};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const EXAMPLES_DIR: &str = "examples";
+pub const MANIFEST_FILE: &str = "manifest.toml";
+
+/// Where a day's examples and their manifest live.
+pub fn examples_dir(day: u32) -> PathBuf {
+    day_dir(day).join(EXAMPLES_DIR)
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "example")]
+    pub examples: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub name: String,
+    /// The input file, relative to the examples directory.
+    pub input: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+    /// Parameters to solve this example with, on top of the solution's
+    /// example values.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    /// Reads the manifest in `dir`; a missing one means no examples yet.
+    pub fn load(dir: &Path) -> Result<Manifest> {
+        let path = dir.join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(Manifest::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let path = dir.join(MANIFEST_FILE);
+        let content = toml::to_string(self).context("Failed to serialize examples manifest")?;
+        fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// The entry called `name`, added without answers or parameters if
+    /// there isn't one yet.
+    pub fn entry_mut(&mut self, name: &str) -> &mut ManifestEntry {
+        let index = match self.examples.iter().position(|e| e.name == name) {
+            Some(index) => index,
+            None => {
+                self.examples.push(ManifestEntry {
+                    name: name.to_string(),
+                    input: format!("{name}.txt"),
+                    part1: None,
+                    part2: None,
+                    params: BTreeMap::new(),
+                });
+                self.examples.len() - 1
+            }
+        };
+        &mut self.examples[index]
+    }
+
+    /// The entry reading `input`, e.g. `example2.txt` for `--example 2`.
+    pub fn entry_for(&self, input: &str) -> Option<&ManifestEntry> {
+        self.examples.iter().find(|entry| entry.input == input)
+    }
+}
+
+impl ManifestEntry {
+    pub fn answer(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The solution's example parameters with this entry's on top.
+    pub fn params<S: Solution>(&self) -> Result<Params> {
+        let mut params = S::example_params();
+        for (name, value) in &self.params {
+            params
+                .set(name, value)
+                .with_context(|| format!("In example {}", self.name))?;
+        }
+        Ok(params)
+    }
+}
+
+/// Solves one part of an example from `dir` and compares it with the
+/// manifest's answer.
+pub fn check<S: Solution>(dir: &Path, entry: &ManifestEntry, part: u32) -> Result<()> {
+    let expected = entry
+        .answer(part)
+        .ok_or_else(|| anyhow!("Example {} has no answer for part {part}", entry.name))?;
+
+    let path = dir.join(&entry.input);
+    let input =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let params = entry.params::<S>()?;
+    let data = S::parse(&input)?;
+    let actual = match part {
+        1 => S::part1(&data, &params)?.render(),
+        _ => S::part2(&data, &params)?.render(),
+    };
+
+    if actual != normalize(expected) {
+        return Err(anyhow!("got {actual}, expected {expected}"));
+    }
+    Ok(())
+}
+
+/// A test per answer in the manifest in `dir`, named like `example1::part2`.
+pub fn trials<S: Solution + 'static>(dir: &Path) -> Result<Vec<Trial>> {
+    let manifest = Manifest::load(dir)?;
+
+    let mut trials = Vec::new();
+    for entry in manifest.examples {
+        for part in [1, 2] {
+            if entry.answer(part).is_none() {
+                continue;
+            }
+            let (dir, entry) = (dir.to_path_buf(), entry.clone());
+            trials.push(Trial::test(
+                format!("{}::part{part}", entry.name),
+                move || check::<S>(&dir, &entry, part).map_err(|err| format!("{err:#}").into()),
+            ));
+        }
+    }
+    Ok(trials)
+}
+
+/// Runs a day's examples as tests, see [`example_tests!`](crate::example_tests).
+pub fn test_main<S: Solution + 'static>() -> ! {
+    let args = Arguments::from_args();
+    let trials = trials::<S>(&examples_dir(S::DAY)).unwrap_or_else(|err| {
+        vec![Trial::test("manifest", move || {
+            Err(format!("{err:#}").into())
+        })]
+    });
+    libtest_mimic::run(&args, trials).exit()
+}
+
+/// Turns `dayXX/examples/manifest.toml` into tests. Goes in
+/// `dayXX/tests/examples.rs`, built with `harness = false`:
+///
+/// ```ignore
+/// runner::example_tests!(day08::Day08);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        fn main() {
+            $crate::examples::test_main::<$solution>()
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+    use crate::{lines, Param, ParseError};
+
+    /// Counts the lines, or only those longer than `min_len`.
+    struct Count;
+
+    impl Solution for Count {
+        const DAY: u32 = 1;
+
+        const PARAMS: &'static [Param] = &[Param {
+            name: "min_len",
+            real: "0",
+            example: "1",
+        }];
+
+        type Input = Vec<String>;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(lines(input).map(|line| line.text.to_string()).collect())
+        }
+
+        fn part1(input: &Self::Input, _params: &Params) -> Result<usize> {
+            Ok(input.len())
+        }
+
+        fn part2(input: &Self::Input, params: &Params) -> Result<usize> {
+            let min_len: usize = params.get("min_len")?;
+            Ok(input.iter().filter(|line| line.len() > min_len).count())
+        }
+    }
+
+    fn examples(name: &str, manifest: &str) -> PathBuf {
+        let dir = temp_dir(name);
+        fs::write(dir.join("example1.txt"), "a\nbb\nccc\n").unwrap();
+        fs::write(dir.join(MANIFEST_FILE), manifest).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = examples(
+            "check",
+            r#"
+[[example]]
+name = "example1"
+input = "example1.txt"
+part1 = "3"
+part2 = "2"
+
+[[example]]
+name = "long-lines"
+input = "example1.txt"
+part1 = "4"
+part2 = "1"
+
+[example.params]
+min_len = "2"
+"#,
+        );
+        let manifest = Manifest::load(&dir).unwrap();
+        let [example1, long_lines] = &manifest.examples[..] else {
+            panic!("expected two examples, got {manifest:?}");
+        };
+
+        assert!(check::<Count>(&dir, example1, 1).is_ok());
+        assert!(check::<Count>(&dir, example1, 2).is_ok());
+        assert_eq!(
+            check::<Count>(&dir, long_lines, 1).unwrap_err().to_string(),
+            "got 3, expected 4"
+        );
+        assert!(check::<Count>(&dir, long_lines, 2).is_ok());
+        assert_eq!(manifest.entry_for("example1.txt"), Some(example1));
+    }
+
+    #[test]
+    fn test_trials_are_named_after_the_examples() {
+        let dir = examples(
+            "trials",
+            "[[example]]\nname = \"example1\"\ninput = \"example1.txt\"\npart2 = \"2\"\n",
+        );
+        let names: Vec<String> = trials::<Count>(&dir)
+            .unwrap()
+            .iter()
+            .map(|trial| trial.name().to_string())
+            .collect();
+
+        assert_eq!(names, vec!["example1::part2"]);
+    }
+
+    #[test]
+    fn test_unknown_params_are_reported() {
+        let entry = ManifestEntry {
+            name: "edge".to_string(),
+            input: "edge.txt".to_string(),
+            part1: None,
+            part2: None,
+            params: BTreeMap::from([("size".to_string(), "3".to_string())]),
+        };
+
+        assert!(entry.params::<Count>().is_err());
+    }
+
+    #[test]
+    fn test_bad_param_values_fail_the_check() {
+        let dir = examples(
+            "bad-param",
+            "[[example]]\nname = \"example1\"\ninput = \"example1.txt\"\npart2 = \"2\"\n\n[example.params]\nmin_len = \"long\"\n",
+        );
+        let manifest = Manifest::load(&dir).unwrap();
+
+        let err = check::<Count>(&dir, &manifest.examples[0], 2).unwrap_err();
+        assert!(err.to_string().contains("for parameter min_len"));
+    }
+}
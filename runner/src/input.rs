@@ -0,0 +1,132 @@
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The workspace root, found from this crate's manifest so it doesn't matter
+/// where a binary is run from: `runner/` sits next to the `dayXX/` crates.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner is inside the workspace")
+}
+
+pub fn day_dir(day: u32) -> PathBuf {
+    workspace_root().join(format!("day{day:02}"))
+}
+
+/// Where a day's puzzle input lives.
+pub fn input_path(day: u32) -> PathBuf {
+    day_dir(day).join("input.txt")
+}
+
+/// Where a stored example lives, as written by the scaffold.
+pub fn example_path(day: u32, example: u32) -> PathBuf {
+    crate::examples::examples_dir(day).join(format!("example{example}.txt"))
+}
+
+/// Where to read a day's input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `dayXX/input.txt`
+    Puzzle,
+    /// `--input <path>`
+    File(PathBuf),
+    /// `--input -` or `-`
+    Stdin,
+    /// `--example [N]`, `dayXX/examples/exampleN.txt`
+    Example(u32),
+}
+
+impl InputSource {
+    /// Takes `--input <path>`, `-` or `--example [N]` out of `args`,
+    /// defaulting to the puzzle input.
+    pub fn from_args(args: &mut Vec<String>) -> Result<InputSource> {
+        let mut sources = Vec::new();
+
+        if let Some(path) = crate::args::take_option(args, "--input")? {
+            sources.push(if path == "-" {
+                InputSource::Stdin
+            } else {
+                InputSource::File(PathBuf::from(path))
+            });
+        }
+        if crate::args::take_flag(args, "-") {
+            sources.push(InputSource::Stdin);
+        }
+        if let Some(i) = args.iter().position(|arg| arg == "--example") {
+            args.remove(i);
+            let number = match args.get(i).map(|arg| arg.parse::<u32>()) {
+                Some(Ok(number)) => {
+                    args.remove(i);
+                    number
+                }
+                _ => 1,
+            };
+            sources.push(InputSource::Example(number));
+        }
+
+        match sources.len() {
+            0 => Ok(InputSource::Puzzle),
+            1 => Ok(sources.remove(0)),
+            _ => Err(anyhow!("Only one of --input, - and --example can be given")),
+        }
+    }
+
+    pub fn read(&self, day: u32) -> Result<String> {
+        let path = match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Failed to read input from stdin")?;
+                return Ok(input);
+            }
+            InputSource::Puzzle => input_path(day),
+            InputSource::File(path) => path.clone(),
+            InputSource::Example(example) => example_path(day, *example),
+        };
+
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::args;
+
+    #[test]
+    fn test_paths_are_inside_the_workspace() {
+        assert!(workspace_root().join("Cargo.toml").exists());
+        assert!(input_path(7).ends_with("day07/input.txt"));
+        assert!(example_path(7, 2).ends_with("day07/examples/example2.txt"));
+    }
+
+    #[test]
+    fn test_from_args() {
+        let source = |list: &[&str]| InputSource::from_args(&mut args(list)).unwrap();
+
+        assert_eq!(source(&[]), InputSource::Puzzle);
+        assert_eq!(
+            source(&["--input", "big.txt"]),
+            InputSource::File(PathBuf::from("big.txt"))
+        );
+        assert_eq!(source(&["--input", "-"]), InputSource::Stdin);
+        assert_eq!(source(&["-"]), InputSource::Stdin);
+        assert_eq!(source(&["--example"]), InputSource::Example(1));
+        assert_eq!(source(&["--example", "2"]), InputSource::Example(2));
+        assert!(InputSource::from_args(&mut args(&["-", "--example"])).is_err());
+    }
+
+    #[test]
+    fn test_example_number_is_optional() {
+        let mut list = args(&["--example", "--time"]);
+
+        assert_eq!(
+            InputSource::from_args(&mut list).unwrap(),
+            InputSource::Example(1)
+        );
+        assert_eq!(list, args(&["--time"]));
+    }
+}
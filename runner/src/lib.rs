@@ -0,0 +1,270 @@
+//! Shared plumbing for the daily solutions: each day implements
+//! [`Solution`] and hands it to [`run`].
+
+mod answer;
+pub mod answers;
+pub mod args;
+pub mod bench;
+pub mod cli;
+pub mod examples;
+mod input;
+pub mod log;
+mod params;
+mod parse;
+#[cfg(test)]
+mod test_support;
+
+pub use answer::{normalize, Answer};
+pub use input::{day_dir, example_path, input_path, workspace_root, InputSource};
+pub use num_bigint::{BigInt, BigUint};
+pub use params::{Param, Params};
+pub use parse::{lines, Line, ParseError};
+
+use anyhow::{anyhow, Result};
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+/// A day's puzzle: how to parse the input and solve both parts from it.
+pub trait Solution {
+    /// Puzzle day, used to find the `dayXX/` directory with its input.
+    const DAY: u32;
+
+    /// Values from the puzzle text that differ between the examples and the
+    /// real input, handed to both parts.
+    const PARAMS: &'static [Param] = &[];
+
+    /// The parsed input, shared by both parts.
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, params: &Params) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input, params: &Params) -> Result<Self::Answer2>;
+
+    /// The parameters to solve the examples with.
+    fn example_params() -> Params {
+        Params::example(Self::PARAMS)
+    }
+}
+
+/// Where the known answers for a day are stored, next to its input.
+pub fn answers_path(day: u32) -> PathBuf {
+    day_dir(day).join("answers.toml")
+}
+
+/// Runs a day from the command line: reads its input from wherever the
+/// arguments say, parses it once and prints the selected parts' answers
+/// as asked (see [`cli::Options::from_args`]).
+pub fn run<S: Solution>() {
+    if let Err(err) = try_run::<S>(env::args().skip(1).collect()) {
+        eprintln!("Error: {err:#}");
+        process::exit(1);
+    }
+}
+
+fn try_run<S: Solution>(mut args: Vec<String>) -> Result<()> {
+    let options = cli::Options::from_args(&mut args)?;
+    if let Some(arg) = args.first() {
+        return Err(anyhow!("Unexpected argument: {arg}"));
+    }
+    log::init(options.verbosity)?;
+    let input = options.input.read(S::DAY)?;
+
+    let mut params = match options.input {
+        InputSource::Example(example) => example_params::<S>(example)?,
+        _ => Params::real(S::PARAMS),
+    };
+    for param in &options.params {
+        params.set_from_arg(param)?;
+    }
+
+    let start = Instant::now();
+    let data = S::parse(&input)?;
+    if let Some(line) = options.render_parse(start.elapsed()) {
+        println!("{line}");
+    }
+
+    for &part in options.parts.selected() {
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&data, &params)?.render(),
+            _ => S::part2(&data, &params)?.render(),
+        };
+        println!(
+            "{}",
+            options.render_answer(S::DAY, part, &answer, start.elapsed())
+        );
+    }
+    Ok(())
+}
+
+/// The parameters for `--example N`, including any the manifest gives that
+/// example.
+fn example_params<S: Solution>(example: u32) -> Result<Params> {
+    let manifest = examples::Manifest::load(&examples::examples_dir(S::DAY))?;
+    match manifest.entry_for(&format!("example{example}.txt")) {
+        Some(entry) => entry.params::<S>(),
+        None => Ok(S::example_params()),
+    }
+}
+
+/// How long each stage of a solution took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// The answers of one run of a solution, rendered for display, and how long
+/// they took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub part1: String,
+    pub part2: String,
+    pub timings: Timings,
+}
+
+/// Parses `input` and solves both parts with the real input's parameters,
+/// timing each stage separately.
+pub fn solve<S: Solution>(input: &str) -> Result<Report> {
+    let params = Params::real(S::PARAMS);
+
+    let start = Instant::now();
+    let data = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = S::part1(&data, &params)?.render();
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = S::part2(&data, &params)?.render();
+    let part2_time = start.elapsed();
+
+    Ok(Report {
+        part1,
+        part2,
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+/// A [`Solution`] with its types erased, so days with different inputs and
+/// answers can be listed and run together.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u32,
+    solve: fn(&str) -> Result<Report>,
+    sample: fn(&str, bench::Stage, &bench::Plan) -> Result<Vec<Duration>>,
+}
+
+impl Day {
+    pub fn new<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            solve: solve::<S>,
+            sample: bench::sample::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: &str) -> Result<Report> {
+        (self.solve)(input)
+    }
+
+    /// Times one stage over many runs, see [`bench::sample`].
+    pub fn sample(
+        &self,
+        input: &str,
+        stage: bench::Stage,
+        plan: &bench::Plan,
+    ) -> Result<Vec<Duration>> {
+        (self.sample)(input, stage, plan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 1;
+
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = String;
+
+        const PARAMS: &'static [Param] = &[Param {
+            name: "unit",
+            real: "numbers",
+            example: "examples",
+        }];
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            lines(input)
+                .map(|line| line.parse(line.text, "number"))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input, _params: &Params) -> Result<u32> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input, params: &Params) -> Result<String> {
+            Ok(format!("{} {}", input.len(), params.get::<String>("unit")?))
+        }
+    }
+
+    #[test]
+    fn test_day_solves_both_parts() {
+        let day = Day::new::<Sum>();
+        let report = day.solve("1\n2\n3\n").unwrap();
+
+        assert_eq!(day.day, 1);
+        assert_eq!(report.part1, "6");
+        assert_eq!(report.part2, "3 numbers");
+        assert_eq!(
+            report.timings.total(),
+            report.timings.parse + report.timings.part1 + report.timings.part2
+        );
+    }
+
+    #[test]
+    fn test_example_params() {
+        let data = Sum::parse("4\n5\n").unwrap();
+
+        assert_eq!(
+            Sum::part2(&data, &Sum::example_params()).unwrap(),
+            "2 examples"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_fail_the_run() {
+        let err = Day::new::<Sum>().solve("1\nx\n").unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<ParseError>().map(|err| err.line),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_answers_path() {
+        assert_eq!(answers_path(7), day_dir(7).join("answers.toml"));
+    }
+}
@@ -0,0 +1,133 @@
+//! Diagnostics for solutions, off by default and written to stderr so they
+//! never mix with the answers. Turned on with `-v` (debug) or `-vv` (trace),
+//! or with the `AOC_LOG` environment variable when no flag is given.
+
+use anyhow::{anyhow, Result};
+use std::env;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The environment variable read when no `-v` is given.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    /// A line or two per step, e.g. per range or per bank.
+    Debug,
+    /// Everything, e.g. each parsed item or a rendered grid row.
+    Trace,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+impl Level {
+    /// The level for a number of `-v`s.
+    pub fn from_verbosity(verbosity: u8) -> Level {
+        match verbosity {
+            0 => Level::Off,
+            1 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Level> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "" | "0" | "off" => Ok(Level::Off),
+            "1" | "debug" => Ok(Level::Debug),
+            "2" | "trace" => Ok(Level::Trace),
+            other => Err(anyhow!(
+                "{ENV_VAR} must be off, debug or trace (or 0-2), not {other}"
+            )),
+        }
+    }
+}
+
+/// Removes every `-v`, `-vv` and `--verbose` from `args`, returning how
+/// verbose to be.
+pub fn take_verbosity(args: &mut Vec<String>) -> u8 {
+    let mut verbosity = 0u8;
+    args.retain(|arg| {
+        let count = match arg.as_str() {
+            "--verbose" => 1,
+            flag if flag.len() > 1
+                && flag.starts_with('-')
+                && flag[1..].bytes().all(|b| b == b'v') =>
+            {
+                flag.len() as u8 - 1
+            }
+            _ => return true,
+        };
+        verbosity = verbosity.saturating_add(count);
+        false
+    });
+    verbosity
+}
+
+/// Sets the level from the number of `-v`s, falling back to `AOC_LOG`.
+pub fn init(verbosity: u8) -> Result<()> {
+    let level = match (verbosity, env::var(ENV_VAR)) {
+        (0, Ok(value)) => value.parse()?,
+        _ => Level::from_verbosity(verbosity),
+    };
+    set_level(level);
+    Ok(())
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Whether messages at `level` are printed.
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Prints to stderr when debug logging is on, taking `format!` arguments.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints to stderr when trace logging is on, taking `format!` arguments.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::args;
+
+    #[test]
+    fn test_take_verbosity() {
+        let mut list = args(&["-v", "--time", "-vv", "-", "--verbose"]);
+
+        assert_eq!(take_verbosity(&mut list), 4);
+        assert_eq!(list, args(&["--time", "-"]));
+        assert_eq!(take_verbosity(&mut args(&["--part", "1"])), 0);
+    }
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Level::from_verbosity(0), Level::Off);
+        assert_eq!(Level::from_verbosity(1), Level::Debug);
+        assert_eq!(Level::from_verbosity(5), Level::Trace);
+        assert_eq!("TRACE".parse::<Level>().unwrap(), Level::Trace);
+        assert_eq!("1".parse::<Level>().unwrap(), Level::Debug);
+        assert!("loud".parse::<Level>().is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A value the puzzle text gives that differs between the examples and the
+/// real input, such as how many connections to make.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// Value for the real puzzle input.
+    pub real: &'static str,
+    /// Value for the examples from the puzzle text.
+    pub example: &'static str,
+}
+
+/// The parameter values a solution runs with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<&'static str, String>,
+}
+
+impl Params {
+    /// The declared parameters with their real input values.
+    pub fn real(declared: &[Param]) -> Params {
+        Params {
+            values: declared
+                .iter()
+                .map(|param| (param.name, param.real.to_string()))
+                .collect(),
+        }
+    }
+
+    /// The declared parameters with their example values.
+    pub fn example(declared: &[Param]) -> Params {
+        Params {
+            values: declared
+                .iter()
+                .map(|param| (param.name, param.example.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Overrides a declared parameter, e.g. from `--param name=value`.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        let Some(current) = self.values.get_mut(name) else {
+            let known: Vec<_> = self.values.keys().copied().collect();
+            return Err(if known.is_empty() {
+                anyhow!("Unknown parameter {name}, this day has none")
+            } else {
+                anyhow!(
+                    "Unknown parameter {name}, this day has {}",
+                    known.join(", ")
+                )
+            });
+        };
+        *current = value.to_string();
+        Ok(())
+    }
+
+    /// Applies a `name=value` override.
+    pub fn set_from_arg(&mut self, arg: &str) -> Result<()> {
+        let (name, value) = arg
+            .split_once('=')
+            .ok_or_else(|| anyhow!("--param expects name=value, not {arg}"))?;
+        self.set(name.trim(), value.trim())
+    }
+
+    /// The value of a parameter the solution declared. Fails if it wasn't
+    /// declared or its value doesn't parse, such as after a bad `--param`.
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T>
+    where
+        T::Err: Display,
+    {
+        let value = self
+            .values
+            .get(name)
+            .ok_or_else(|| anyhow!("Parameter {name} isn't declared in PARAMS"))?;
+        value
+            .parse()
+            .map_err(|err| anyhow!("Invalid value {value:?} for parameter {name}: {err}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[Param {
+        name: "connections",
+        real: "1000",
+        example: "10",
+    }];
+
+    #[test]
+    fn test_real_and_example_values() {
+        assert_eq!(
+            Params::real(PARAMS).get::<usize>("connections").unwrap(),
+            1000
+        );
+        assert_eq!(
+            Params::example(PARAMS).get::<usize>("connections").unwrap(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_overrides() {
+        let mut params = Params::real(PARAMS);
+        params.set_from_arg("connections=500").unwrap();
+
+        assert_eq!(params.get::<usize>("connections").unwrap(), 500);
+        assert!(params.set_from_arg("connections").is_err());
+        assert!(params.set("size", "7").is_err());
+        assert!(Params::default().set("size", "7").is_err());
+    }
+
+    #[test]
+    fn test_get_undeclared() {
+        let err = Params::real(PARAMS).get::<usize>("size").unwrap_err();
+        assert_eq!(err.to_string(), "Parameter size isn't declared in PARAMS");
+    }
+
+    #[test]
+    fn test_bad_override_is_an_error() {
+        let mut params = Params::real(PARAMS);
+        params.set_from_arg("connections=abc").unwrap();
+
+        let err = params.get::<usize>("connections").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid value \"abc\" for parameter connections: invalid digit found in string"
+        );
+    }
+}
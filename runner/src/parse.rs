@@ -0,0 +1,156 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Input that doesn't match the puzzle's format, pointing at the offending
+/// text so it renders with a caret under it:
+///
+/// ```text
+/// invalid coordinate "4x2" at line 3, column 5
+///   |
+/// 3 | 162,4x2,12
+///   |     ^^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The whole line the error is on.
+    pub text: String,
+    /// How many characters to underline, at least one.
+    pub width: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about something missing at the end of `input`, such as a
+    /// section that never started.
+    pub fn at_end(input: &str, message: impl Into<String>) -> ParseError {
+        let last = lines(input).last().unwrap_or(Line {
+            number: 1,
+            text: "",
+        });
+        last.error(&last.text[last.text.len()..], message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        writeln!(
+            f,
+            "{} at line {}, column {}",
+            self.message, self.line, self.column
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{number} | {}", self.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// One line of an input, knowing where it is so errors can point into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// The lines of `input`, numbered.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    /// An error about `part`, which should be a slice of this line (as
+    /// handed out by `split` and friends) so the caret lands on it.
+    pub fn error(&self, part: &str, message: impl Into<String>) -> ParseError {
+        let start = (part.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&offset| offset + part.len() <= self.text.len())
+            .or_else(|| self.text.find(part))
+            .unwrap_or(0);
+
+        ParseError {
+            line: self.number,
+            column: self.text[..start].chars().count() + 1,
+            text: self.text.to_string(),
+            width: part.chars().count().max(1),
+            message: message.into(),
+        }
+    }
+
+    /// Parses `part` of this line as a `what`, e.g. `line.parse(field, "coordinate")`.
+    pub fn parse<T: FromStr>(&self, part: &'a str, what: &str) -> Result<T, ParseError> {
+        part.parse()
+            .map_err(|_| self.error(part, format!("invalid {what} {part:?}")))
+    }
+
+    /// Splits the line in two at the first `separator`, naming what was
+    /// expected if it isn't there.
+    pub fn split_once(
+        &self,
+        separator: char,
+        what: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(separator)
+            .ok_or_else(|| self.error(self.text, format!("expected {what}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_points_at_the_part() {
+        let line = lines("1,2,3\n162,4x2,12\n").nth(1).unwrap();
+        let field = line.text.split(',').nth(1).unwrap();
+        let err = line.parse::<i64>(field, "coordinate").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.width), (2, 5, 3));
+        assert_eq!(
+            err.to_string(),
+            concat!(
+                "invalid coordinate \"4x2\" at line 2, column 5\n",
+                "  |\n",
+                "2 | 162,4x2,12\n",
+                "  |     ^^^",
+            )
+        );
+    }
+
+    #[test]
+    fn test_split_once() {
+        let line = lines("3-5").next().unwrap();
+
+        assert_eq!(line.split_once('-', "a range").unwrap(), ("3", "5"));
+        assert_eq!(
+            line.split_once(',', "a pair").unwrap_err().message,
+            "expected a pair"
+        );
+    }
+
+    #[test]
+    fn test_at_end() {
+        let err = ParseError::at_end("3-5\n10-14\n", "expected a blank line");
+        assert_eq!((err.line, err.column, err.width), (2, 6, 1));
+
+        let err = ParseError::at_end("", "expected ranges");
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
@@ -0,0 +1,130 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default cache location, relative to the repository root.
+pub const CACHE_DIR: &str = ".aoc-cache";
+
+const PROBLEM_FILE: &str = "problem.html";
+const INPUT_FILE: &str = "input.txt";
+const META_FILE: &str = "meta.toml";
+
+/// When and how a cached file was fetched, used to revalidate it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FetchMeta {
+    pub fetched_at: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl FetchMeta {
+    pub fn now(etag: Option<String>, last_modified: Option<String>) -> FetchMeta {
+        FetchMeta {
+            fetched_at: Utc::now().to_rfc3339(),
+            etag,
+            last_modified,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Meta {
+    problem: Option<FetchMeta>,
+    input: Option<FetchMeta>,
+}
+
+/// Raw pages and inputs for one puzzle, stored under
+/// `{root}/{year}/{day:02}/`.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(root: &Path, year: i32, day: u32) -> Cache {
+        Cache {
+            dir: root.join(year.to_string()).join(format!("{day:02}")),
+        }
+    }
+
+    pub fn problem(&self) -> Result<Option<(String, FetchMeta)>> {
+        let meta = self.read_meta()?.problem;
+        Ok(self
+            .read(PROBLEM_FILE)?
+            .map(|html| (html, meta.unwrap_or_default())))
+    }
+
+    pub fn store_problem(&self, html: &str, meta: FetchMeta) -> Result<()> {
+        self.write(PROBLEM_FILE, html)?;
+        self.update_meta(|m| m.problem = Some(meta))
+    }
+
+    pub fn input(&self) -> Result<Option<String>> {
+        self.read(INPUT_FILE)
+    }
+
+    pub fn store_input(&self, input: &str, meta: FetchMeta) -> Result<()> {
+        self.write(INPUT_FILE, input)?;
+        self.update_meta(|m| m.input = Some(meta))
+    }
+
+    fn read(&self, file: &str) -> Result<Option<String>> {
+        let path = self.dir.join(file);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        fs::read_to_string(&path)
+            .map(Some)
+            .context(format!("Failed to read cached {}", path.display()))
+    }
+
+    fn write(&self, file: &str, content: &str) -> Result<()> {
+        fs::create_dir_all(&self.dir).context("Failed to create cache directory")?;
+        let path = self.dir.join(file);
+        fs::write(&path, content).context(format!("Failed to write cached {}", path.display()))
+    }
+
+    fn read_meta(&self) -> Result<Meta> {
+        match self.read(META_FILE)? {
+            Some(content) => toml::from_str(&content).context("Failed to parse cache metadata"),
+            None => Ok(Meta::default()),
+        }
+    }
+
+    fn update_meta(&self, update: impl FnOnce(&mut Meta)) -> Result<()> {
+        let mut meta = self.read_meta()?;
+        update(&mut meta);
+        let content = toml::to_string(&meta).context("Failed to serialize cache metadata")?;
+        self.write(META_FILE, &content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    #[test]
+    fn test_round_trip() {
+        let root = temp_dir("cache-round-trip");
+        let cache = Cache::new(&root, 2025, 8);
+
+        assert!(cache.problem().unwrap().is_none());
+        assert!(cache.input().unwrap().is_none());
+
+        let meta = FetchMeta::now(Some("\"abc\"".to_string()), None);
+        cache.store_problem("<html></html>", meta.clone()).unwrap();
+        cache
+            .store_input("1,2,3\n", FetchMeta::now(None, None))
+            .unwrap();
+
+        assert!(root.join("2025/08/problem.html").exists());
+        assert_eq!(
+            cache.problem().unwrap(),
+            Some(("<html></html>".to_string(), meta))
+        );
+        assert_eq!(cache.input().unwrap(), Some("1,2,3\n".to_string()));
+    }
+}
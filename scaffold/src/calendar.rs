@@ -0,0 +1,164 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, TimeZone, Utc};
+use std::io::{self, Write};
+use std::thread;
+
+/// Puzzles unlock at midnight US Eastern Standard Time (UTC-5).
+fn unlock_zone() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+/// Number of puzzles in the given year's event, if there was one.
+pub fn event_length(year: i32) -> Option<u32> {
+    match year {
+        ..=2014 => None,
+        2015..=2024 => Some(25),
+        _ => Some(12),
+    }
+}
+
+/// The instant the given day's puzzle unlocks.
+pub fn unlock_time(year: i32, day: u32) -> DateTime<Utc> {
+    unlock_zone()
+        .with_ymd_and_hms(year, 12, day, 0, 0, 0)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayStatus {
+    Unlocked,
+    Locked { unlocks_at: DateTime<Utc> },
+}
+
+/// Checks that `day` is part of the `year` event and whether it has
+/// unlocked yet.
+pub fn day_status(year: i32, day: u32, now: DateTime<Utc>) -> Result<DayStatus> {
+    let Some(length) = event_length(year) else {
+        return Err(anyhow!("There was no Advent of Code event in {year}"));
+    };
+    if day == 0 || day > length {
+        return Err(anyhow!(
+            "Day {day} is out of range, the {year} event has days 1-{length}"
+        ));
+    }
+
+    let unlocks_at = unlock_time(year, day);
+    if now >= unlocks_at {
+        Ok(DayStatus::Unlocked)
+    } else {
+        Ok(DayStatus::Locked { unlocks_at })
+    }
+}
+
+/// The day to scaffold when none is given: today's puzzle if the event is
+/// running, otherwise the next one to unlock.
+pub fn default_day(year: i32, now: DateTime<Utc>) -> Result<u32> {
+    let Some(length) = event_length(year) else {
+        return Err(anyhow!("There was no Advent of Code event in {year}"));
+    };
+
+    let first = unlock_time(year, 1);
+    let last = unlock_time(year, length);
+    if now < first - Duration::days(1) || now >= last + Duration::days(1) {
+        return Err(anyhow!(
+            "The {year} event isn't running, please specify a day number"
+        ));
+    }
+
+    let today = now.with_timezone(&unlock_zone());
+    if now < first {
+        Ok(1)
+    } else {
+        Ok(today.day().min(length))
+    }
+}
+
+pub fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.num_seconds().max(0);
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+/// Blocks until `unlocks_at`, showing a countdown on a single line.
+pub fn wait_for_unlock(day: u32, unlocks_at: DateTime<Utc>) {
+    loop {
+        let remaining = unlocks_at - Utc::now();
+        if remaining <= Duration::zero() {
+            break;
+        }
+
+        print!(
+            "\r⏳ Day {day} unlocks in {}",
+            format_countdown(remaining + Duration::milliseconds(999))
+        );
+        let _ = io::stdout().flush();
+
+        let tick = remaining.min(Duration::seconds(1));
+        thread::sleep(tick.to_std().unwrap_or_default());
+    }
+    println!("\r🔓 Day {day} is unlocked!          ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_event_length() {
+        assert_eq!(event_length(2014), None);
+        assert_eq!(event_length(2015), Some(25));
+        assert_eq!(event_length(2024), Some(25));
+        assert_eq!(event_length(2025), Some(12));
+    }
+
+    #[test]
+    fn test_unlock_time_is_midnight_eastern() {
+        assert_eq!(unlock_time(2025, 8), utc(12, 8, 5, 0));
+    }
+
+    #[test]
+    fn test_day_status() {
+        assert_eq!(
+            day_status(2025, 8, utc(12, 8, 4, 59)).unwrap(),
+            DayStatus::Locked {
+                unlocks_at: utc(12, 8, 5, 0)
+            }
+        );
+        assert_eq!(
+            day_status(2025, 8, utc(12, 8, 5, 0)).unwrap(),
+            DayStatus::Unlocked
+        );
+        assert!(day_status(2025, 13, utc(12, 31, 0, 0)).is_err());
+        assert!(day_status(2025, 0, utc(12, 31, 0, 0)).is_err());
+        assert!(day_status(2013, 1, utc(12, 31, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_default_day() {
+        // 23:30 Eastern on Dec 7th is still day 7
+        assert_eq!(default_day(2025, utc(12, 8, 4, 30)).unwrap(), 7);
+        assert_eq!(default_day(2025, utc(12, 8, 5, 0)).unwrap(), 8);
+        // Waiting for the first puzzle
+        assert_eq!(default_day(2025, utc(11, 30, 20, 0)).unwrap(), 1);
+        // The day after the last puzzle still defaults to it
+        assert_eq!(default_day(2025, utc(12, 13, 1, 0)).unwrap(), 12);
+        assert!(default_day(2025, utc(12, 20, 0, 0)).is_err());
+        assert!(default_day(2025, utc(7, 1, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::seconds(3723)), "01:02:03");
+        assert_eq!(format_countdown(Duration::seconds(-5)), "00:00:00");
+    }
+}
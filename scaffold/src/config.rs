@@ -0,0 +1,130 @@
+use anyhow::{Context, Result};
+use runner::args::take_option;
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+pub const DEFAULT_YEAR: i32 = 2025;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Repo-level config file, looked up in the directory the scaffold runs from.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Which event to talk to and where it lives.
+///
+/// Each setting is resolved in order from command-line flags (`--year`,
+/// `--url`), environment variables (`AOC_YEAR`, `AOC_URL`), `aoc.toml`, and
+/// finally the built-in defaults.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub year: i32,
+    pub base_url: String,
+}
+
+/// Settings as they appear in `aoc.toml`; every key is optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileConfig {
+    year: Option<i32>,
+    base_url: Option<String>,
+}
+
+impl Config {
+    /// Resolves the config, consuming any `--year`/`--url` flags from `args`.
+    pub fn load(args: &mut Vec<String>) -> Result<Config> {
+        let year_flag = take_option(args, "--year")?;
+        let url_flag = take_option(args, "--url")?;
+
+        let file = if Path::new(CONFIG_FILE).exists() {
+            let content =
+                fs::read_to_string(CONFIG_FILE).context(format!("Failed to read {CONFIG_FILE}"))?;
+            Some(content)
+        } else {
+            None
+        };
+
+        Config::resolve(
+            year_flag.or_else(|| env::var("AOC_YEAR").ok()),
+            url_flag.or_else(|| env::var("AOC_URL").ok()),
+            file.as_deref(),
+        )
+    }
+
+    fn resolve(
+        year: Option<String>,
+        base_url: Option<String>,
+        file: Option<&str>,
+    ) -> Result<Config> {
+        let file: FileConfig = match file {
+            Some(content) => {
+                toml::from_str(content).context(format!("Failed to parse {CONFIG_FILE}"))?
+            }
+            None => FileConfig::default(),
+        };
+
+        let year = match year {
+            Some(year) => year
+                .parse()
+                .context(format!("Invalid year provided: {year}"))?,
+            None => file.year.unwrap_or(DEFAULT_YEAR),
+        };
+
+        let base_url = base_url
+            .or(file.base_url)
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+            .trim_end_matches('/')
+            .to_string();
+
+        Ok(Config { year, base_url })
+    }
+
+    pub fn puzzle_url(&self, day: u32) -> String {
+        format!("{}/{}/day/{day}", self.base_url, self.year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let config = Config::resolve(None, None, None).unwrap();
+        assert_eq!(
+            config,
+            Config {
+                year: DEFAULT_YEAR,
+                base_url: DEFAULT_BASE_URL.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_file_overrides_defaults() {
+        let file = "year = 2024\nbase_url = \"http://localhost:8080/\"\n";
+        let config = Config::resolve(None, None, Some(file)).unwrap();
+
+        assert_eq!(config.year, 2024);
+        assert_eq!(config.base_url, "http://localhost:8080");
+    }
+
+    #[test]
+    fn test_explicit_values_override_file() {
+        let file = "year = 2024\nbase_url = \"http://localhost:8080\"\n";
+        let config = Config::resolve(
+            Some("2019".to_string()),
+            Some("http://127.0.0.1:1234".to_string()),
+            Some(file),
+        )
+        .unwrap();
+
+        assert_eq!(config.year, 2019);
+        assert_eq!(config.puzzle_url(3), "http://127.0.0.1:1234/2019/day/3");
+    }
+
+    #[test]
+    fn test_rejects_unknown_file_keys() {
+        assert!(Config::resolve(None, None, Some("yaer = 2024\n")).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+use reqwest::blocking::Response;
+use reqwest::header::RETRY_AFTER;
+use reqwest::StatusCode;
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+/// Why a request to Advent of Code failed.
+#[derive(Debug)]
+pub enum FetchError {
+    /// The puzzle hasn't unlocked yet (AoC answers 404).
+    NotReleased,
+    /// The session cookie is missing, expired or invalid.
+    SessionExpired,
+    /// Too many requests; holds the server's `Retry-After` if it sent one.
+    RateLimited(Option<Duration>),
+    /// AoC had a problem of its own (5xx).
+    Server(StatusCode),
+    /// The request never got a response.
+    Network(reqwest::Error),
+    /// Any other status.
+    UnexpectedStatus(StatusCode),
+    /// A successful response whose body isn't what was asked for, such as an
+    /// HTML page where a puzzle input was expected.
+    UnexpectedContent(String),
+}
+
+impl FetchError {
+    /// Whether trying again later might succeed.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            FetchError::RateLimited(_) | FetchError::Server(_) | FetchError::Network(_)
+        )
+    }
+
+    /// Classifies a non-successful response.
+    pub fn from_response(response: &Response) -> FetchError {
+        let status = response.status();
+        match status {
+            StatusCode::NOT_FOUND => FetchError::NotReleased,
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                FetchError::SessionExpired
+            }
+            StatusCode::TOO_MANY_REQUESTS => FetchError::RateLimited(
+                response
+                    .headers()
+                    .get(RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.parse().ok())
+                    .map(Duration::from_secs),
+            ),
+            _ if status.is_server_error() => FetchError::Server(status),
+            _ => FetchError::UnexpectedStatus(status),
+        }
+    }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NotReleased => write!(f, "The puzzle hasn't been released yet"),
+            FetchError::SessionExpired => write!(
+                f,
+                "Your session cookie has expired or is invalid, \
+                 get a fresh one from your browser and update AOC_SESSION"
+            ),
+            FetchError::RateLimited(Some(wait)) => {
+                write!(f, "Rate limited, retry after {}s", wait.as_secs())
+            }
+            FetchError::RateLimited(None) => write!(f, "Rate limited, try again later"),
+            FetchError::Server(status) => write!(f, "Advent of Code server error: HTTP {status}"),
+            FetchError::Network(err) => write!(f, "Network error: {err}"),
+            FetchError::UnexpectedStatus(status) => write!(f, "Unexpected response: HTTP {status}"),
+            FetchError::UnexpectedContent(reason) => write!(f, "Unexpected content: {reason}"),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Network(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(err: reqwest::Error) -> FetchError {
+        FetchError::Network(err)
+    }
+}
+
+/// Refuses bodies that can't be a puzzle input, so login prompts and error
+/// pages never end up in `input.txt`.
+pub fn validate_input(body: &str) -> Result<(), FetchError> {
+    if body.contains("Puzzle inputs differ by user") || body.contains("Please log in") {
+        return Err(FetchError::SessionExpired);
+    }
+    if body.contains("before it unlocks") {
+        return Err(FetchError::NotReleased);
+    }
+
+    let start = body.trim_start().to_ascii_lowercase();
+    if start.starts_with("<!doctype") || start.starts_with("<html") {
+        return Err(FetchError::UnexpectedContent(
+            "got an HTML page instead of a puzzle input".to_string(),
+        ));
+    }
+    if body.trim().is_empty() {
+        return Err(FetchError::UnexpectedContent(
+            "the puzzle input is empty".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Refuses a problem page served to a logged-out visitor. AoC still answers
+/// 200 for those, but they only show Part 1 and none of your answers, so
+/// caching one would hide progress the real page has.
+pub fn validate_problem_page(body: &str) -> Result<(), FetchError> {
+    if body.contains("identify yourself") || body.contains("Please log in") {
+        return Err(FetchError::SessionExpired);
+    }
+    Ok(())
+}
+
+/// How often, and how patiently, transient failures are retried.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Also retry [`FetchError::NotReleased`], for requests made right as a
+    /// puzzle unlocks while the local clock may be a little ahead of AoC's.
+    pub retry_not_released: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            attempts: 4,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            retry_not_released: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether a request that failed with `err` is worth trying again.
+    pub fn should_retry(&self, err: &FetchError) -> bool {
+        err.is_transient() || (self.retry_not_released && matches!(err, FetchError::NotReleased))
+    }
+
+    /// Delay before retrying after `attempt` (1-based) failed with `err`:
+    /// the server's `Retry-After` when given, otherwise exponential backoff.
+    pub fn delay(&self, attempt: u32, err: &FetchError) -> Duration {
+        let delay = match err {
+            FetchError::RateLimited(Some(wait)) => *wait,
+            _ => self.base_delay * 2u32.saturating_pow(attempt - 1),
+        };
+        delay.min(self.max_delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_input() {
+        assert!(validate_input("1,2,3\n4,5,6\n").is_ok());
+        assert!(matches!(
+            validate_input(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+            ),
+            Err(FetchError::SessionExpired)
+        ));
+        assert!(matches!(
+            validate_input("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"),
+            Err(FetchError::NotReleased)
+        ));
+        assert!(matches!(
+            validate_input("<!DOCTYPE html>\n<html><body>500</body></html>"),
+            Err(FetchError::UnexpectedContent(_))
+        ));
+        assert!(matches!(
+            validate_input("\n"),
+            Err(FetchError::UnexpectedContent(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_problem_page() {
+        assert!(validate_problem_page("<article><h2>--- Day 1 ---</h2></article>").is_ok());
+        assert!(matches!(
+            validate_problem_page(
+                "<article>..</article><p>To play, please identify yourself via one of these services:</p>"
+            ),
+            Err(FetchError::SessionExpired)
+        ));
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let policy = RetryPolicy {
+            attempts: 5,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(5),
+            retry_not_released: false,
+        };
+        let server_error = FetchError::Server(StatusCode::BAD_GATEWAY);
+
+        assert_eq!(policy.delay(1, &server_error), Duration::from_secs(1));
+        assert_eq!(policy.delay(2, &server_error), Duration::from_secs(2));
+        assert_eq!(policy.delay(4, &server_error), Duration::from_secs(5));
+        assert_eq!(
+            policy.delay(1, &FetchError::RateLimited(Some(Duration::from_secs(3)))),
+            Duration::from_secs(3)
+        );
+    }
+
+    #[test]
+    fn test_transient_errors() {
+        assert!(FetchError::Server(StatusCode::SERVICE_UNAVAILABLE).is_transient());
+        assert!(FetchError::RateLimited(None).is_transient());
+        assert!(!FetchError::SessionExpired.is_transient());
+        assert!(!FetchError::NotReleased.is_transient());
+    }
+
+    #[test]
+    fn test_should_retry_not_released_right_after_unlock() {
+        let mut policy = RetryPolicy::default();
+        assert!(!policy.should_retry(&FetchError::NotReleased));
+        assert!(policy.should_retry(&FetchError::RateLimited(None)));
+
+        policy.retry_not_released = true;
+        assert!(policy.should_retry(&FetchError::NotReleased));
+        assert!(!policy.should_retry(&FetchError::SessionExpired));
+    }
+}
@@ -0,0 +1,199 @@
+use anyhow::{Context, Result};
+use runner::examples::{Manifest, ManifestEntry, EXAMPLES_DIR};
+use scraper::{ElementRef, Html, Selector};
+use std::fs;
+use std::path::Path;
+
+/// The example from one part of the puzzle: the first `<pre><code>` block
+/// and the highlighted `<code><em>` answer that goes with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+/// Extracts one example per unlocked part. Part 2 rarely repeats the example
+/// input, so it falls back to the input from Part 1.
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let document = Html::parse_document(html);
+    let article_selector = Selector::parse("article.day-desc").unwrap();
+
+    let mut examples: Vec<Example> = Vec::new();
+    for article in document.select(&article_selector) {
+        let input = example_input(article)
+            .or_else(|| examples.first().map(|example| example.input.clone()));
+
+        if let Some(input) = input {
+            examples.push(Example {
+                input,
+                answer: example_answer(article),
+            });
+        }
+    }
+
+    examples
+}
+
+fn example_input(article: ElementRef) -> Option<String> {
+    let selector = Selector::parse("pre > code").unwrap();
+    article
+        .select(&selector)
+        .next()
+        .map(|code| code.text().collect())
+}
+
+/// AoC highlights the example's answer as `<code><em>..</em></code>`; the
+/// last one in the article is the final answer.
+fn example_answer(article: ElementRef) -> Option<String> {
+    let selector = Selector::parse("code > em").unwrap();
+    article
+        .select(&selector)
+        .filter(|em| {
+            em.parent()
+                .and_then(ElementRef::wrap)
+                .is_some_and(|code| code.children().count() == 1)
+        })
+        .last()
+        .map(|em| em.text().collect::<String>().trim().to_string())
+}
+
+/// Writes the extracted examples to `day_dir/examples/`, one input file per
+/// distinct example, and records their answers in the manifest. Existing
+/// entries are updated in place: their parameters are kept, and so is any
+/// answer the page didn't give this time. Entries added by hand are left
+/// alone.
+pub fn write_examples(day_dir: &Path, examples: &[Example]) -> Result<()> {
+    let Some(first) = examples.first() else {
+        return Ok(());
+    };
+
+    let dir = day_dir.join(EXAMPLES_DIR);
+    fs::create_dir_all(&dir).context("Failed to create examples directory")?;
+
+    let mut manifest = Manifest::load(&dir)?;
+
+    let entry = record_example(&dir, &mut manifest, "example1", &first.input)?;
+    set_answer(&mut entry.part1, &first.answer);
+
+    if let Some(second) = examples.get(1) {
+        let entry = if second.input == first.input {
+            manifest.entry_mut("example1")
+        } else {
+            record_example(&dir, &mut manifest, "example2", &second.input)?
+        };
+        set_answer(&mut entry.part2, &second.answer);
+    }
+
+    manifest.save(&dir)
+}
+
+/// Writes `input` to `{name}.txt` and points the manifest entry `name` at it.
+fn record_example<'m>(
+    dir: &Path,
+    manifest: &'m mut Manifest,
+    name: &str,
+    input: &str,
+) -> Result<&'m mut ManifestEntry> {
+    let entry = manifest.entry_mut(name);
+    entry.input = format!("{name}.txt");
+    fs::write(dir.join(&entry.input), input)
+        .with_context(|| format!("Failed to write {}", entry.input))?;
+    Ok(entry)
+}
+
+/// Records an extracted answer, keeping the old one if none was found.
+fn set_answer(recorded: &mut Option<String>, extracted: &Option<String>) {
+    if extracted.is_some() {
+        recorded.clone_from(extracted);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+    use runner::examples::MANIFEST_FILE;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 7: Laboratories ---</h2>
+<p>For example:</p>
+<pre><code>.......S.......
+.......^.......
+</code></pre>
+<p>In this example, a tachyon beam is split a total of <code><em>21</em></code> times.</p>
+</article>
+<p>Your puzzle answer was <code>1600</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Between <code><em>5</em></code> and <code>9</code>, you'd end up on <code><em>40</em></code> different timelines.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract_examples(PAGE);
+        let input = ".......S.......\n.......^.......\n".to_string();
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    input: input.clone(),
+                    answer: Some("21".to_string())
+                },
+                Example {
+                    input,
+                    answer: Some("40".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_examples_keeps_manual_entries() {
+        let day_dir = temp_dir("examples");
+        let examples_dir = day_dir.join(EXAMPLES_DIR);
+        fs::create_dir_all(&examples_dir).unwrap();
+        fs::write(
+            examples_dir.join(MANIFEST_FILE),
+            "[[example]]\nname = \"edge\"\ninput = \"edge.txt\"\npart1 = \"0\"\n\n[example.params]\nsize = \"3\"\n",
+        )
+        .unwrap();
+
+        write_examples(&day_dir, &extract_examples(PAGE)).unwrap();
+        write_examples(&day_dir, &extract_examples(PAGE)).unwrap();
+
+        let manifest = Manifest::load(&examples_dir).unwrap();
+        assert_eq!(manifest.examples.len(), 2);
+        assert_eq!(manifest.examples[0].name, "edge");
+        assert_eq!(manifest.examples[0].params["size"], "3");
+        assert_eq!(manifest.examples[1].part1.as_deref(), Some("21"));
+        assert_eq!(manifest.examples[1].part2.as_deref(), Some("40"));
+        assert!(examples_dir.join("example1.txt").exists());
+        assert!(!examples_dir.join("example2.txt").exists());
+    }
+
+    #[test]
+    fn test_write_examples_updates_entries_in_place() {
+        let day_dir = temp_dir("examples-in-place");
+        let examples_dir = day_dir.join(EXAMPLES_DIR);
+        fs::create_dir_all(&examples_dir).unwrap();
+        fs::write(
+            examples_dir.join(MANIFEST_FILE),
+            "[[example]]\nname = \"example1\"\ninput = \"old.txt\"\npart1 = \"20\"\npart2 = \"40\"\n\n[example.params]\nsize = \"7\"\n",
+        )
+        .unwrap();
+
+        // Only part 1 is unlocked on this fetch
+        let examples = extract_examples(PAGE);
+        write_examples(&day_dir, &examples[..1]).unwrap();
+        write_examples(&day_dir, &examples[..1]).unwrap();
+
+        let manifest = Manifest::load(&examples_dir).unwrap();
+        assert_eq!(manifest.examples.len(), 1);
+        let entry = &manifest.examples[0];
+        assert_eq!(entry.input, "example1.txt");
+        assert_eq!(entry.part1.as_deref(), Some("21"));
+        assert_eq!(entry.part2.as_deref(), Some("40"));
+        assert_eq!(entry.params["size"], "7");
+    }
+}
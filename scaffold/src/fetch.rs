@@ -0,0 +1,333 @@
+use crate::cache::{Cache, FetchMeta};
+use crate::config::Config;
+use crate::error::{self, FetchError, RetryPolicy};
+use anyhow::{anyhow, Context, Result};
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use std::path::PathBuf;
+use std::thread;
+
+/// Fetches puzzle pages and inputs through the on-disk cache.
+///
+/// Without a client the fetcher is offline and only serves cached files.
+pub struct Fetcher<'a> {
+    pub client: Option<&'a Client>,
+    pub config: &'a Config,
+    pub cache_dir: PathBuf,
+    pub retry: RetryPolicy,
+}
+
+impl Fetcher<'_> {
+    /// Sends the request built by `request`, retrying transient failures
+    /// with backoff. `304 Not Modified` counts as success.
+    fn send(&self, request: impl Fn() -> RequestBuilder) -> Result<Response, FetchError> {
+        let mut attempt = 1;
+        loop {
+            let result = request()
+                .send()
+                .map_err(FetchError::from)
+                .and_then(|response| {
+                    let status = response.status();
+                    if status.is_success() || status == StatusCode::NOT_MODIFIED {
+                        Ok(response)
+                    } else {
+                        Err(FetchError::from_response(&response))
+                    }
+                });
+
+            match result {
+                Err(err) if self.retry.should_retry(&err) && attempt < self.retry.attempts => {
+                    let delay = self.retry.delay(attempt, &err);
+                    println!("⚠️  {err}, retrying in {}s...", delay.as_secs_f32());
+                    thread::sleep(delay);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn cache(&self, day: u32) -> Cache {
+        Cache::new(&self.cache_dir, self.config.year, day)
+    }
+
+    /// Returns the raw problem page HTML, revalidating any cached copy with
+    /// `If-None-Match`/`If-Modified-Since` so unchanged pages aren't re-sent.
+    pub fn problem_html(&self, day: u32) -> Result<String> {
+        let cache = self.cache(day);
+        let cached = cache.problem()?;
+
+        let Some(client) = self.client else {
+            return cached
+                .map(|(html, _)| html)
+                .ok_or_else(|| anyhow!("Day {day} problem page is not cached, can't run offline"));
+        };
+
+        let response = self
+            .send(|| {
+                let mut request = client.get(self.config.puzzle_url(day));
+                if let Some((_, meta)) = &cached {
+                    if let Some(etag) = &meta.etag {
+                        request = request.header(IF_NONE_MATCH, etag);
+                    }
+                    if let Some(last_modified) = &meta.last_modified {
+                        request = request.header(IF_MODIFIED_SINCE, last_modified);
+                    }
+                }
+                request
+            })
+            .context("Failed to fetch problem page")?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some((html, _)) = cached {
+                return Ok(html);
+            }
+            return Err(anyhow!(
+                "Got 304 Not Modified without a cached problem page"
+            ));
+        }
+
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+        };
+        let meta = FetchMeta::now(header(ETAG), header(LAST_MODIFIED));
+        let html = response.text().context("Failed to read problem page")?;
+        error::validate_problem_page(&html).context("Refusing to save problem page")?;
+        cache.store_problem(&html, meta)?;
+
+        Ok(html)
+    }
+
+    /// Returns the puzzle input. Inputs never change, so a cached input is
+    /// used as-is and never requested again.
+    pub fn input(&self, day: u32) -> Result<String> {
+        let cache = self.cache(day);
+        if let Some(input) = cache.input()? {
+            return Ok(input);
+        }
+
+        let Some(client) = self.client else {
+            return Err(anyhow!("Day {day} input is not cached, can't run offline"));
+        };
+
+        let url = format!("{}/input", self.config.puzzle_url(day));
+        let response = self
+            .send(|| client.get(&url))
+            .context("Failed to fetch input")?;
+
+        let input = response.text().context("Failed to read input text")?;
+        error::validate_input(&input).context("Refusing to save input")?;
+        cache.store_input(&input, FetchMeta::now(None, None))?;
+
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{stand_in_server, temp_dir, Response};
+    use std::time::Duration;
+
+    fn no_delay() -> RetryPolicy {
+        RetryPolicy {
+            attempts: 3,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            retry_not_released: false,
+        }
+    }
+
+    fn fetcher<'a>(
+        client: Option<&'a Client>,
+        config: &'a Config,
+        cache_dir: PathBuf,
+    ) -> Fetcher<'a> {
+        Fetcher {
+            client,
+            config,
+            cache_dir,
+            retry: no_delay(),
+        }
+    }
+
+    fn config(base_url: String) -> Config {
+        Config {
+            year: 2025,
+            base_url,
+        }
+    }
+
+    #[test]
+    fn test_problem_html_revalidates_cache() {
+        let (base_url, server) = stand_in_server(vec![
+            Response::ok("<article>v1</article>").header("ETag", "\"v1\""),
+            Response::status(304, ""),
+        ]);
+        let config = config(base_url);
+        let client = Client::new();
+        let fetcher = fetcher(Some(&client), &config, temp_dir("fetch-problem"));
+
+        assert_eq!(fetcher.problem_html(1).unwrap(), "<article>v1</article>");
+        assert_eq!(fetcher.problem_html(1).unwrap(), "<article>v1</article>");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].line, "GET /2025/day/1 HTTP/1.1");
+        assert_eq!(requests[0].header("if-none-match"), None);
+        assert_eq!(requests[1].header("if-none-match"), Some("\"v1\""));
+    }
+
+    #[test]
+    fn test_logged_out_page_keeps_the_cached_one() {
+        let (base_url, server) = stand_in_server(vec![
+            Response::ok("<article>v1</article>").header("ETag", "\"v1\""),
+            Response::ok(
+                "<article>v1</article><p>To play, please identify yourself via one of these services:</p>",
+            ),
+        ]);
+        let config = config(base_url);
+        let client = Client::new();
+        let cache_dir = temp_dir("fetch-logged-out");
+        let fetcher = fetcher(Some(&client), &config, cache_dir.clone());
+
+        fetcher.problem_html(1).unwrap();
+        let err = fetcher.problem_html(1).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::SessionExpired)
+        ));
+        server.join().unwrap();
+
+        let (html, _) = Cache::new(&cache_dir, 2025, 1).problem().unwrap().unwrap();
+        assert_eq!(html, "<article>v1</article>");
+    }
+
+    #[test]
+    fn test_input_is_fetched_once() {
+        let (base_url, server) = stand_in_server(vec![Response::ok("1\n2\n")]);
+        let config = config(base_url);
+        let client = Client::new();
+        let fetcher = fetcher(Some(&client), &config, temp_dir("fetch-input"));
+
+        assert_eq!(fetcher.input(2).unwrap(), "1\n2\n");
+        assert_eq!(
+            server.join().unwrap()[0].line,
+            "GET /2025/day/2/input HTTP/1.1"
+        );
+
+        // The server is gone now, so this can only come from the cache.
+        assert_eq!(fetcher.input(2).unwrap(), "1\n2\n");
+    }
+
+    #[test]
+    fn test_offline_uses_cache_only() {
+        let config = config("http://127.0.0.1:9".to_string());
+        let cache_dir = temp_dir("fetch-offline");
+        let fetcher = fetcher(None, &config, cache_dir.clone());
+
+        assert!(fetcher.problem_html(3).is_err());
+        assert!(fetcher.input(3).is_err());
+
+        let cache = Cache::new(&cache_dir, 2025, 3);
+        cache
+            .store_problem("<article></article>", FetchMeta::now(None, None))
+            .unwrap();
+        cache
+            .store_input("42\n", FetchMeta::now(None, None))
+            .unwrap();
+
+        assert_eq!(fetcher.problem_html(3).unwrap(), "<article></article>");
+        assert_eq!(fetcher.input(3).unwrap(), "42\n");
+    }
+
+    #[test]
+    fn test_retries_transient_failures() {
+        let (base_url, server) = stand_in_server(vec![
+            Response::status(502, "Bad Gateway"),
+            Response::status(503, "Unavailable"),
+            Response::ok("1\n2\n"),
+        ]);
+        let config = config(base_url);
+        let client = Client::new();
+        let fetcher = fetcher(Some(&client), &config, temp_dir("fetch-retry"));
+
+        assert_eq!(fetcher.input(4).unwrap(), "1\n2\n");
+        assert_eq!(server.join().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_gives_up_after_bounded_retries() {
+        let (base_url, server) = stand_in_server(vec![
+            Response::status(500, ""),
+            Response::status(500, ""),
+            Response::status(500, ""),
+        ]);
+        let config = config(base_url);
+        let client = Client::new();
+        let fetcher = fetcher(Some(&client), &config, temp_dir("fetch-give-up"));
+
+        let err = fetcher.input(4).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::Server(StatusCode::INTERNAL_SERVER_ERROR))
+        ));
+        assert_eq!(server.join().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_does_not_retry_or_save_login_page() {
+        let (base_url, server) = stand_in_server(vec![Response::ok(
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        )]);
+        let config = config(base_url);
+        let client = Client::new();
+        let cache_dir = temp_dir("fetch-login");
+        let fetcher = fetcher(Some(&client), &config, cache_dir.clone());
+
+        let err = fetcher.input(5).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::SessionExpired)
+        ));
+        assert_eq!(server.join().unwrap().len(), 1);
+        assert!(Cache::new(&cache_dir, 2025, 5).input().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_not_released() {
+        let (base_url, server) = stand_in_server(vec![Response::status(404, "Not Found")]);
+        let config = config(base_url);
+        let client = Client::new();
+        let fetcher = fetcher(Some(&client), &config, temp_dir("fetch-not-released"));
+
+        let err = fetcher.problem_html(12).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::NotReleased)
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_not_released_is_retried_right_after_unlock() {
+        let (base_url, server) = stand_in_server(vec![
+            Response::status(404, "Not Found"),
+            Response::ok("<article>day 12</article>"),
+        ]);
+        let config = config(base_url);
+        let client = Client::new();
+        let mut fetcher = fetcher(Some(&client), &config, temp_dir("fetch-just-unlocked"));
+        fetcher.retry.retry_not_released = true;
+
+        assert_eq!(
+            fetcher.problem_html(12).unwrap(),
+            "<article>day 12</article>"
+        );
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+}
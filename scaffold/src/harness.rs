@@ -0,0 +1,112 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+pub const HARNESS_DIR: &str = "harness";
+
+/// Adds a new day to the `harness` crate, as a dependency and as an entry in
+/// its list of days, so it's included when running every day. Registering a
+/// day twice is a no-op.
+pub fn register_day(root: &Path, day: u32) -> Result<()> {
+    let name = format!("day{day:02}");
+    let dir = root.join(HARNESS_DIR);
+
+    let cargo_toml_path = dir.join("Cargo.toml");
+    let mut cargo_toml =
+        fs::read_to_string(&cargo_toml_path).context("Failed to read harness Cargo.toml")?;
+    if !cargo_toml.contains(&format!("\n{name} = ")) {
+        if !cargo_toml.ends_with('\n') {
+            cargo_toml.push('\n');
+        }
+        cargo_toml.push_str(&format!("{name} = {{ path = \"../{name}\" }}\n"));
+        fs::write(&cargo_toml_path, cargo_toml).context("Failed to write harness Cargo.toml")?;
+    }
+
+    let days_rs_path = dir.join("src/days.rs");
+    let days_rs = fs::read_to_string(&days_rs_path).context("Failed to read harness days.rs")?;
+    let entry = format!("        Day::new::<{name}::Day{day:02}>(),");
+    if days_rs.contains(&entry) {
+        return Ok(());
+    }
+
+    // Keep the list in day order: the new entry goes before the first later
+    // day, or at the end of the list
+    let mut lines: Vec<&str> = days_rs.lines().collect();
+    let position = lines
+        .iter()
+        .position(|line| {
+            registered_day(line).is_some_and(|registered| registered > day) || line.trim() == "]"
+        })
+        .context("Couldn't find the list of days in harness days.rs")?;
+    lines.insert(position, &entry);
+
+    fs::write(&days_rs_path, lines.join("\n") + "\n").context("Failed to write harness days.rs")
+}
+
+/// The day an entry such as `Day::new::<day07::Day07>(),` registers.
+fn registered_day(line: &str) -> Option<u32> {
+    line.trim()
+        .strip_prefix("Day::new::<day")?
+        .get(..2)?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    const CARGO_TOML: &str = "\
+[dependencies]
+runner.workspace = true
+
+day01 = { path = \"../day01\" }
+day05 = { path = \"../day05\" }
+";
+
+    const DAYS_RS: &str = "\
+use runner::Day;
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day01::Day01>(),
+        Day::new::<day05::Day05>(),
+    ]
+}
+";
+
+    #[test]
+    fn test_register_day() {
+        let root = temp_dir("harness");
+        fs::create_dir_all(root.join("harness/src")).unwrap();
+        fs::write(root.join("harness/Cargo.toml"), CARGO_TOML).unwrap();
+        fs::write(root.join("harness/src/days.rs"), DAYS_RS).unwrap();
+
+        register_day(&root, 3).unwrap();
+        register_day(&root, 12).unwrap();
+        register_day(&root, 3).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(root.join("harness/Cargo.toml")).unwrap(),
+            format!(
+                "{CARGO_TOML}day03 = {{ path = \"../day03\" }}\nday12 = {{ path = \"../day12\" }}\n"
+            )
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("harness/src/days.rs")).unwrap(),
+            "\
+use runner::Day;
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day01::Day01>(),
+        Day::new::<day03::Day03>(),
+        Day::new::<day05::Day05>(),
+        Day::new::<day12::Day12>(),
+    ]
+}
+"
+        );
+    }
+}
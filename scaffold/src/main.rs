@@ -1,15 +1,31 @@
 // This scaffold was generated with LLM.
 
+mod cache;
+mod calendar;
+mod config;
+mod error;
+mod examples;
+mod fetch;
+mod harness;
+mod markdown;
+mod puzzle;
+mod submit;
+#[cfg(test)]
+mod test_support;
+
 use anyhow::{anyhow, Context, Result};
-use chrono::{Datelike, Local};
+use calendar::DayStatus;
+use chrono::Utc;
+use config::Config;
+use fetch::Fetcher;
+use puzzle::Progress;
 use reqwest::blocking::Client;
-use scraper::{Html, Selector};
+use runner::args::take_flag;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
-
-const YEAR: i32 = 2025;
-const AOC_URL: &str = "https://adventofcode.com";
+use std::process;
+use submit::SubmitOutcome;
 
 fn get_session_cookie() -> Result<String> {
     env::var("AOC_SESSION").context(
@@ -19,76 +35,15 @@ fn get_session_cookie() -> Result<String> {
     )
 }
 
-fn get_day_from_args_or_current() -> Result<u32> {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() > 1 {
-        args[1]
+fn get_day_from_args_or_current(args: &[String], config: &Config) -> Result<u32> {
+    if !args.is_empty() {
+        args[0]
             .parse::<u32>()
             .context("Invalid day number provided")
     } else {
-        // Get current day if in December
-        let now = Local::now();
-        if now.month() == 12 {
-            Ok(now.day())
-        } else {
-            Err(anyhow!("Not December, please specify a day number"))
-        }
-    }
-}
-
-fn fetch_problem_text(client: &Client, day: u32, part: u32) -> Result<String> {
-    let url = format!("{AOC_URL}/{YEAR}/day/{day}");
-    let response = client
-        .get(&url)
-        .send()
-        .context("Failed to fetch problem page")?;
-
-    if !response.status().is_success() {
-        return Err(anyhow!(
-            "Failed to fetch problem: HTTP {}",
-            response.status()
-        ));
-    }
-
-    let html = response.text()?;
-    let document = Html::parse_document(&html);
-
-    // Select all article tags (AOC uses <article> for problem descriptions)
-    let article_selector = Selector::parse("article.day-desc").unwrap();
-    let articles: Vec<_> = document.select(&article_selector).collect();
-
-    if articles.is_empty() {
-        return Err(anyhow!("No problem description found on the page"));
+        // Get the current (or next) puzzle if the event is running
+        calendar::default_day(config.year, Utc::now())
     }
-
-    // Part 1 is the first article, Part 2 is the second (if it exists)
-    let article = if part == 2 && articles.len() >= 2 {
-        articles[1]
-    } else if part == 1 {
-        articles[0]
-    } else {
-        return Err(anyhow!("Part {part} not available yet"));
-    };
-
-    // Convert HTML to a more readable format
-    let text = article.text().collect::<Vec<_>>().join("");
-
-    // Add some formatting
-    let formatted = format!("# Day {} - Part {}\n\n{}\n", day, part, text.trim());
-
-    Ok(formatted)
-}
-
-fn fetch_input(client: &Client, day: u32) -> Result<String> {
-    let url = format!("{AOC_URL}/{YEAR}/day/{day}/input");
-    let response = client.get(&url).send().context("Failed to fetch input")?;
-
-    if !response.status().is_success() {
-        return Err(anyhow!("Failed to fetch input: HTTP {}", response.status()));
-    }
-
-    response.text().context("Failed to read input text")
 }
 
 fn create_day_structure(day: u32) -> Result<PathBuf> {
@@ -111,7 +66,13 @@ edition.workspace = true
 authors.workspace = true
 
 [dependencies]
+anyhow.workspace = true
 itertools.workspace = true
+runner.workspace = true
+
+[[test]]
+name = "examples"
+harness = false
 "#
     );
 
@@ -120,53 +81,46 @@ itertools.workspace = true
     Ok(())
 }
 
-fn write_main_rs(day_dir: &Path, day: u32) -> Result<()> {
-    let main_rs = format!(
-        r#"use std::fs;
+fn write_lib_rs(day_dir: &Path, day: u32) -> Result<()> {
+    let lib_rs = format!(
+        r#"use anyhow::Result;
+use runner::{{lines, Params, ParseError, Solution}};
 
-fn parse_input(input: &str) -> Vec<String> {{
-    input.lines().map(|s| s.to_string()).collect()
-}}
+pub struct Day{day:02};
 
-fn part1(data: &[String]) -> usize {{
-    // TODO: Implement part 1
-    0
-}}
+impl Solution for Day{day:02} {{
+    const DAY: u32 = {day};
 
-fn part2(data: &[String]) -> usize {{
-    // TODO: Implement part 2
-    0
-}}
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-fn main() {{
-    let input = fs::read_to_string("day{day:02}/input.txt")
-        .expect("Failed to read input file");
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        lines(input).map(|line| Ok(line.text.to_string())).collect()
+    }}
 
-    let data = parse_input(&input);
+    fn part1(data: &Self::Input, _params: &Params) -> Result<usize> {{
+        // TODO: Implement part 1
+        Ok(0)
+    }}
 
-    println!("Part 1: {{}}", part1(&data));
-    println!("Part 2: {{}}", part2(&data));
+    fn part2(data: &Self::Input, _params: &Params) -> Result<usize> {{
+        // TODO: Implement part 2
+        Ok(0)
+    }}
 }}
+"#
+    );
 
-#[cfg(test)]
-mod tests {{
-    use super::*;
-
-    const EXAMPLE: &str = "\
-TODO: Add example input here
-";
+    fs::write(day_dir.join("src/lib.rs"), lib_rs).context("Failed to write lib.rs")?;
 
-    #[test]
-    fn test_part1() {{
-        let data = parse_input(EXAMPLE);
-        assert_eq!(part1(&data), 0);
-    }}
+    Ok(())
+}
 
-    #[test]
-    fn test_part2() {{
-        let data = parse_input(EXAMPLE);
-        assert_eq!(part2(&data), 0);
-    }}
+fn write_main_rs(day_dir: &Path, day: u32) -> Result<()> {
+    let main_rs = format!(
+        r#"fn main() {{
+    runner::run::<day{day:02}::Day{day:02}>();
 }}
 "#
     );
@@ -176,23 +130,24 @@ TODO: Add example input here
     Ok(())
 }
 
-fn write_problem_md(day_dir: &Path, problem_text: &str, part: u32) -> Result<()> {
-    let problem_file = if part == 1 {
-        day_dir.join("problem.md")
-    } else {
-        day_dir.join("problem_part2.md")
-    };
+/// The examples in `examples/manifest.toml` run as tests through
+/// `runner::example_tests!`.
+fn write_example_tests(day_dir: &Path, day: u32) -> Result<()> {
+    let tests_dir = day_dir.join("tests");
+    fs::create_dir_all(&tests_dir).context("Failed to create tests directory")?;
 
-    // If part 2, append to existing file or create new
-    if part == 2 && problem_file.exists() {
-        let existing = fs::read_to_string(&problem_file)?;
-        fs::write(
-            &problem_file,
-            format!("{existing}\n\n---\n\n{problem_text}"),
-        )?;
-    } else {
-        fs::write(&problem_file, problem_text)
-            .context(format!("Failed to write problem.md for part {part}"))?;
+    let examples_rs = format!("runner::example_tests!(day{day:02}::Day{day:02});\n");
+    fs::write(tests_dir.join("examples.rs"), examples_rs)
+        .context("Failed to write tests/examples.rs")
+}
+
+fn write_problem_md(day_dir: &Path, problem: &str) -> Result<()> {
+    fs::write(day_dir.join("problem.md"), problem).context("Failed to write problem.md")?;
+
+    // Part 2 used to live in its own file
+    let legacy_part2 = day_dir.join("problem_part2.md");
+    if legacy_part2.exists() {
+        fs::remove_file(&legacy_part2).context("Failed to remove problem_part2.md")?;
     }
 
     Ok(())
@@ -207,6 +162,7 @@ fn day_exists(day: u32) -> bool {
     PathBuf::from(format!("day{day:02}")).exists()
 }
 
+#[allow(dead_code)]
 fn update_workspace_members(day: u32) -> Result<()> {
     let cargo_toml_path = PathBuf::from("Cargo.toml");
     let content =
@@ -252,17 +208,12 @@ fn update_workspace_members(day: u32) -> Result<()> {
     Ok(())
 }
 
-fn main() -> Result<()> {
-    let day = get_day_from_args_or_current()?;
-    let _session = get_session_cookie()?;
-
-    println!("🎄 Scaffolding Advent of Code {YEAR} - Day {day}");
-
+fn build_client(session: &str) -> Result<Client> {
     // Create HTTP client with session cookie
     let mut headers = reqwest::header::HeaderMap::new();
     headers.insert(
         reqwest::header::COOKIE,
-        format!("session={_session}").parse()?,
+        format!("session={session}").parse()?,
     );
 
     let client = Client::builder()
@@ -270,52 +221,144 @@ fn main() -> Result<()> {
         .default_headers(headers)
         .build()?;
 
-    let day_dir_exists = day_exists(day);
+    Ok(client)
+}
+
+fn submit(client: &Client, config: &Config, args: &[String]) -> Result<()> {
+    let [day, part, answer] = args else {
+        return Err(anyhow!("Usage: scaffold submit <day> <part> <answer>"));
+    };
+    let day: u32 = day.parse().context("Invalid day number provided")?;
+    let part: u32 = part.parse().context("Invalid part number provided")?;
+    if part != 1 && part != 2 {
+        return Err(anyhow!("Part must be 1 or 2"));
+    }
+
+    let answer = runner::normalize(answer);
+    if answer.is_empty() {
+        return Err(anyhow!("The answer is empty"));
+    }
+
+    println!("📤 Submitting {answer} for Day {day} Part {part}...");
+    let outcome = submit::submit_answer(client, config, day, part, &answer)?;
+    println!("{outcome}");
 
-    // Determine which part we're fetching
-    let part = if day_dir_exists {
-        println!("📂 Day {day:02} already exists, fetching Part 2...");
-        2
+    if outcome == SubmitOutcome::Correct {
+        runner::answers::record(&runner::answers_path(day), part, &answer)?;
+        println!("📝 Answer recorded for ./aoc verify");
+    }
+
+    process::exit(outcome.exit_code());
+}
+
+fn main() -> Result<()> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let config = Config::load(&mut args)?;
+    let offline = take_flag(&mut args, "--offline");
+    let wait = take_flag(&mut args, "--wait");
+
+    if args.first().is_some_and(|arg| arg == "submit") {
+        if offline {
+            return Err(anyhow!("Can't submit answers with --offline"));
+        }
+        let client = build_client(&get_session_cookie()?)?;
+        return submit(&client, &config, &args[1..]);
+    }
+
+    let client = if offline {
+        None
     } else {
-        println!("📂 Creating new day {day:02} structure...");
-        1
+        Some(build_client(&get_session_cookie()?)?)
     };
+    let mut fetcher = Fetcher {
+        client: client.as_ref(),
+        config: &config,
+        cache_dir: PathBuf::from(cache::CACHE_DIR),
+        retry: error::RetryPolicy::default(),
+    };
+
+    let day = get_day_from_args_or_current(&args, &config)?;
+
+    println!("🎄 Scaffolding Advent of Code {} - Day {day}", config.year);
 
-    // Fetch problem description
-    println!("📥 Fetching problem description (Part {part})...");
-    let problem_text = fetch_problem_text(&client, day, part)?;
+    if let DayStatus::Locked { unlocks_at } = calendar::day_status(config.year, day, Utc::now())? {
+        if !wait {
+            return Err(anyhow!(
+                "Day {day} unlocks in {} (at {unlocks_at}), rerun with --wait to fetch it as soon as it does",
+                calendar::format_countdown(unlocks_at - Utc::now())
+            ));
+        }
+        calendar::wait_for_unlock(day, unlocks_at);
+        // Our clock may be a little ahead of AoC's, so a 404 now just means
+        // "not quite yet"
+        fetcher.retry.retry_not_released = true;
+    }
+
+    // The page tells us which parts are unlocked and which are solved
+    println!("📥 Fetching problem page...");
+    let problem_html = fetcher.problem_html(day)?;
+    let progress = Progress::from_html(&problem_html);
+    if progress.unlocked_parts == 0 {
+        return Err(anyhow!("No problem description found on the page"));
+    }
+    println!("{progress}");
 
-    // Fetch input
-    println!("📥 Fetching input...");
-    let input = fetch_input(&client, day)?;
+    let examples = examples::extract_examples(&problem_html);
 
     // Create or update day structure
-    let day_dir = if !day_dir_exists {
+    let day_dir = if !day_exists(day) {
+        println!("📂 Creating new day {day:02} structure...");
         let dir = create_day_structure(day)?;
         write_cargo_toml(&dir, day)?;
+        write_lib_rs(&dir, day)?;
         write_main_rs(&dir, day)?;
+        write_example_tests(&dir, day)?;
 
         // We don't need to do this because we match all dirs with a splat
         // Update workspace Cargo.toml
         // println!("📝 Updating workspace Cargo.toml...");
         // update_workspace_members(day)?;
 
+        println!("📝 Registering day {day:02} with the harness...");
+        harness::register_day(Path::new("."), day)?;
+
         dir
     } else {
+        println!("📂 Day {day:02} already exists, filling in what's missing...");
         PathBuf::from(format!("day{day:02}"))
     };
 
-    // Write problem and input
-    write_problem_md(&day_dir, &problem_text, part)?;
-    write_input(&day_dir, &input)?;
+    // Regenerate the problem from the page so it always has every unlocked part
+    let problem = puzzle::problem_markdown(&problem_html, &config.base_url)?;
+    write_problem_md(&day_dir, &problem)?;
+    println!(
+        "📖 Problem (Part {}) saved to: day{day:02}/problem.md",
+        (1..=progress.unlocked_parts)
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>()
+            .join(" & ")
+    );
 
-    println!("✅ Success!");
-    println!("\n📖 Problem saved to: day{day:02}/problem.md");
-    if part == 2 {
-        println!("   (Part 2 appended to problem.md)");
+    examples::write_examples(&day_dir, &examples)?;
+    if !examples.is_empty() {
+        println!("🧪 Examples saved to: day{day:02}/examples/");
+    }
+
+    // The page lists the answers that were accepted, so keep them for verify
+    for (part, answer) in (1..).zip(&progress.answers) {
+        runner::answers::record(&runner::answers_path(day), part, answer)?;
+    }
+
+    // Fetch input if it's missing
+    if !day_dir.join("input.txt").exists() {
+        println!("📥 Fetching input...");
+        let input = fetcher.input(day)?;
+        write_input(&day_dir, &input)?;
+        println!("📄 Input saved to: day{day:02}/input.txt");
     }
-    println!("📄 Input saved to: day{day:02}/input.txt");
-    println!("🦀 Code ready at: day{day:02}/src/main.rs");
+
+    println!("✅ Success!");
+    println!("🦀 Code ready at: day{day:02}/src/lib.rs");
     println!("\n🚀 Run with: cargo run --bin day{day:02}");
 
     Ok(())
@@ -0,0 +1,325 @@
+use ego_tree::NodeRef;
+use scraper::{ElementRef, Node};
+
+/// Converts a puzzle `<article>` into Markdown.
+///
+/// Example blocks (`<pre><code>`) become fenced code blocks with their
+/// whitespace untouched, `<em>` becomes bold, and lists, links and headings
+/// map onto their Markdown equivalents. Relative links are resolved against
+/// `base_url`.
+pub fn to_markdown(article: ElementRef, base_url: &str) -> String {
+    let converter = Converter { base_url };
+    let mut markdown = converter.blocks(*article).join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+struct Converter<'a> {
+    base_url: &'a str,
+}
+
+/// Elements that start a new Markdown block rather than flowing inline.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "p",
+    "pre",
+    "ul",
+    "ol",
+    "blockquote",
+    "div",
+    "section",
+    "article",
+];
+
+impl Converter<'_> {
+    /// Converts the children of `parent` into a list of Markdown blocks,
+    /// gathering loose inline content into paragraphs.
+    fn blocks(&self, parent: NodeRef<Node>) -> Vec<String> {
+        let mut blocks = Vec::new();
+        let mut pending = String::new();
+
+        for child in parent.children() {
+            match child.value() {
+                Node::Element(element) if BLOCK_ELEMENTS.contains(&element.name()) => {
+                    push_paragraph(&mut blocks, &mut pending);
+                    blocks.extend(self.block(child, element.name()));
+                }
+                _ => pending.push_str(&self.inline(child)),
+            }
+        }
+        push_paragraph(&mut blocks, &mut pending);
+
+        blocks
+    }
+
+    fn block(&self, node: NodeRef<Node>, name: &str) -> Vec<String> {
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level: usize = name[1..].parse().unwrap();
+                vec![format!(
+                    "{} {}",
+                    "#".repeat(level),
+                    self.inline_children(node).trim()
+                )]
+            }
+            "p" => {
+                let text = self.inline_children(node);
+                if text.trim().is_empty() {
+                    Vec::new()
+                } else {
+                    vec![text.trim().to_string()]
+                }
+            }
+            "pre" => vec![fenced(&text_content(node))],
+            "ul" => vec![self.list(node, false)],
+            "ol" => vec![self.list(node, true)],
+            "blockquote" => vec![self
+                .blocks(node)
+                .join("\n\n")
+                .lines()
+                .map(|line| format!("> {line}").trim_end().to_string())
+                .collect::<Vec<_>>()
+                .join("\n")],
+            _ => self.blocks(node),
+        }
+    }
+
+    fn list(&self, node: NodeRef<Node>, ordered: bool) -> String {
+        let mut items = Vec::new();
+
+        for item in node.children() {
+            let Node::Element(element) = item.value() else {
+                continue;
+            };
+            if element.name() != "li" {
+                continue;
+            }
+
+            let marker = if ordered {
+                format!("{}. ", items.len() + 1)
+            } else {
+                "- ".to_string()
+            };
+            let indent = " ".repeat(marker.len());
+            let content = self.blocks(item).join("\n\n");
+
+            let mut lines = content.lines();
+            let mut entry = format!("{marker}{}", lines.next().unwrap_or_default());
+            for line in lines {
+                entry.push('\n');
+                if !line.is_empty() {
+                    entry.push_str(&indent);
+                    entry.push_str(line);
+                }
+            }
+            items.push(entry);
+        }
+
+        items.join("\n")
+    }
+
+    fn inline_children(&self, node: NodeRef<Node>) -> String {
+        node.children().map(|child| self.inline(child)).collect()
+    }
+
+    fn inline(&self, node: NodeRef<Node>) -> String {
+        match node.value() {
+            Node::Text(text) => escape(&collapse_whitespace(text)),
+            Node::Element(element) => match element.name() {
+                "em" | "strong" | "b" | "i" => wrap("**", &self.inline_children(node)),
+                "code" => {
+                    let code = text_content(node);
+                    // AoC highlights answers as <code><em>40</em></code>
+                    if has_only_emphasis(node) {
+                        wrap("**", &code_span(&code))
+                    } else {
+                        code_span(&code)
+                    }
+                }
+                "a" => {
+                    let text = self.inline_children(node);
+                    match element.attr("href") {
+                        Some(href) => format!("[{text}]({})", self.resolve(href)),
+                        None => text,
+                    }
+                }
+                "br" => "\n".to_string(),
+                _ => self.inline_children(node),
+            },
+            _ => String::new(),
+        }
+    }
+
+    fn resolve(&self, href: &str) -> String {
+        if href.starts_with('/') {
+            format!("{}{href}", self.base_url)
+        } else {
+            href.to_string()
+        }
+    }
+}
+
+fn push_paragraph(blocks: &mut Vec<String>, pending: &mut String) {
+    let paragraph = pending.trim();
+    if !paragraph.is_empty() {
+        blocks.push(paragraph.to_string());
+    }
+    pending.clear();
+}
+
+/// All text below `node`, exactly as written in the HTML.
+fn text_content(node: NodeRef<Node>) -> String {
+    node.descendants()
+        .filter_map(|descendant| match descendant.value() {
+            Node::Text(text) => Some(&**text),
+            _ => None,
+        })
+        .collect()
+}
+
+fn has_only_emphasis(node: NodeRef<Node>) -> bool {
+    let mut children = node.children();
+    let only_child = children.next();
+    children.next().is_none()
+        && only_child.is_some_and(
+            |child| matches!(child.value(), Node::Element(element) if element.name() == "em"),
+        )
+}
+
+/// Surrounds `text` with `marker`, keeping any outer whitespace outside so
+/// the Markdown emphasis stays valid.
+fn wrap(marker: &str, text: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+    format!("{leading}{marker}{trimmed}{marker}{trailing}")
+}
+
+fn code_span(code: &str) -> String {
+    let fence = "`".repeat(longest_run(code, '`') + 1);
+    if code.starts_with('`') || code.ends_with('`') {
+        format!("{fence} {code} {fence}")
+    } else {
+        format!("{fence}{code}{fence}")
+    }
+}
+
+fn fenced(code: &str) -> String {
+    let fence = "`".repeat(longest_run(code, '`').max(2) + 1);
+    let newline = if code.ends_with('\n') { "" } else { "\n" };
+    format!("{fence}\n{code}{newline}{fence}")
+}
+
+fn longest_run(text: &str, c: char) -> usize {
+    text.split(|ch| ch != c)
+        .map(str::len)
+        .max()
+        .unwrap_or_default()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut in_whitespace = false;
+
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                collapsed.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            collapsed.push(c);
+            in_whitespace = false;
+        }
+    }
+
+    collapsed
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scraper::{Html, Selector};
+
+    fn convert(html: &str) -> String {
+        let document = Html::parse_document(html);
+        let selector = Selector::parse("article").unwrap();
+        to_markdown(
+            document.select(&selector).next().unwrap(),
+            "https://adventofcode.com",
+        )
+    }
+
+    #[test]
+    fn test_paragraphs_and_emphasis() {
+        let markdown = convert(
+            "<article><h2>--- Day 1: Test ---</h2>\n<p>The answer is <em>very</em>\n   important, see <a href=\"/2025/about\">about</a>.</p></article>",
+        );
+
+        assert_eq!(
+            markdown,
+            "## --- Day 1: Test ---\n\nThe answer is **very** important, see [about](https://adventofcode.com/2025/about).\n"
+        );
+    }
+
+    #[test]
+    fn test_example_block_keeps_whitespace() {
+        let markdown = convert(
+            "<article><p>For example:</p>\n<pre><code>123 328  51 64\n 45 64  387 23\n<em>*</em>   +   *   +\n</code></pre></article>",
+        );
+
+        assert_eq!(
+            markdown,
+            "For example:\n\n```\n123 328  51 64\n 45 64  387 23\n*   +   *   +\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_highlighted_answer() {
+        let markdown = convert(
+            "<article><p>In total, there are <code><em>40</em></code> paths and <code>a*b</code>.</p></article>",
+        );
+
+        assert_eq!(markdown, "In total, there are **`40`** paths and `a*b`.\n");
+    }
+
+    #[test]
+    fn test_lists() {
+        let markdown = convert(
+            "<article><ul>\n<li>The first <em>item</em>.</li>\n<li><p>The second.</p><ol><li>Nested</li></ol></li>\n</ul></article>",
+        );
+
+        assert_eq!(
+            markdown,
+            "- The first **item**.\n- The second.\n\n  1. Nested\n"
+        );
+    }
+
+    #[test]
+    fn test_escapes_markdown_in_text() {
+        assert_eq!(
+            convert("<article><p>2 * 3_4</p></article>"),
+            "2 \\* 3\\_4\n"
+        );
+    }
+}
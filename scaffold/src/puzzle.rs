@@ -0,0 +1,159 @@
+use crate::markdown;
+use anyhow::{anyhow, Result};
+use scraper::{Html, Selector};
+use std::fmt;
+
+/// How far along a puzzle is, as shown on its page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    /// Number of `article.day-desc` elements, i.e. parts whose description
+    /// is visible.
+    pub unlocked_parts: u32,
+    /// Accepted answers from the "Your puzzle answer was" paragraphs, one per
+    /// star earned.
+    pub answers: Vec<String>,
+}
+
+impl Progress {
+    pub fn from_html(html: &str) -> Progress {
+        let document = Html::parse_document(html);
+        let article_selector = Selector::parse("article.day-desc").unwrap();
+        let paragraph_selector = Selector::parse("main > p").unwrap();
+        let code_selector = Selector::parse("code").unwrap();
+
+        let unlocked_parts = document.select(&article_selector).count() as u32;
+        let answers = document
+            .select(&paragraph_selector)
+            .filter(|p| {
+                p.text()
+                    .collect::<String>()
+                    .contains("Your puzzle answer was")
+            })
+            .filter_map(|p| p.select(&code_selector).next())
+            .map(|code| code.text().collect::<String>().trim().to_string())
+            .collect();
+
+        Progress {
+            unlocked_parts,
+            answers,
+        }
+    }
+
+    pub fn stars(&self) -> usize {
+        self.answers.len()
+    }
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stars = "⭐".repeat(self.stars());
+        write!(f, "{stars} {}/2 stars", self.stars())?;
+        for (i, answer) in self.answers.iter().enumerate() {
+            write!(f, "\n   Part {} answer: {answer}", i + 1)?;
+        }
+        if self.stars() < 2 {
+            let next = self.stars() + 1;
+            if next as u32 <= self.unlocked_parts {
+                write!(f, "\n   Part {next} is unlocked and unsolved")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Renders every unlocked part of the puzzle page as one Markdown document:
+/// the puzzle title, then a `## Part N` section per part. The output only
+/// depends on the page, so regenerating it is idempotent.
+pub fn problem_markdown(html: &str, base_url: &str) -> Result<String> {
+    let document = Html::parse_document(html);
+    let article_selector = Selector::parse("article.day-desc").unwrap();
+    let heading_selector = Selector::parse("h2").unwrap();
+
+    let articles: Vec<_> = document.select(&article_selector).collect();
+    let Some(first) = articles.first() else {
+        return Err(anyhow!("No problem description found on the page"));
+    };
+
+    let title = first
+        .select(&heading_selector)
+        .next()
+        .map(|h2| h2.text().collect::<String>())
+        .unwrap_or_default();
+    let title = title.trim().trim_matches('-').trim();
+
+    let mut problem = format!("# {title}\n");
+    for (i, article) in articles.iter().enumerate() {
+        let body = markdown::to_markdown(*article, base_url);
+        // Each article opens with its "--- Part Two ---" style heading,
+        // which the section heading replaces
+        let body = match body.split_once("\n\n") {
+            Some((heading, rest)) if heading.starts_with("## ") => rest,
+            _ => body.as_str(),
+        };
+        problem.push_str(&format!("\n## Part {}\n\n{body}", i + 1));
+    }
+
+    Ok(problem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTICLE_1: &str = "<article class=\"day-desc\"><h2>--- Day 1 ---</h2></article>";
+    const ARTICLE_2: &str =
+        "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2></article>";
+
+    fn page(body: &str) -> String {
+        format!("<html><body><main>{body}</main></body></html>")
+    }
+
+    #[test]
+    fn test_problem_markdown() {
+        let html = page(
+            "<article class=\"day-desc\"><h2>--- Day 7: Laboratories ---</h2><p>Part one.</p></article>\
+             <p>Your puzzle answer was <code>1600</code>.</p>\
+             <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Part two.</p></article>",
+        );
+
+        assert_eq!(
+            problem_markdown(&html, "").unwrap(),
+            "# Day 7: Laboratories\n\n## Part 1\n\nPart one.\n\n## Part 2\n\nPart two.\n"
+        );
+    }
+
+    #[test]
+    fn test_problem_markdown_requires_article() {
+        assert!(problem_markdown(&page("<p>Please log in.</p>"), "").is_err());
+    }
+
+    #[test]
+    fn test_unsolved() {
+        let progress = Progress::from_html(&page(&format!(
+            "{ARTICLE_1}<form><input name=\"answer\"/></form>"
+        )));
+
+        assert_eq!(progress.unlocked_parts, 1);
+        assert_eq!(progress.stars(), 0);
+    }
+
+    #[test]
+    fn test_part1_solved() {
+        let progress = Progress::from_html(&page(&format!(
+            "{ARTICLE_1}<p>Your puzzle answer was <code>1600</code>.</p>{ARTICLE_2}"
+        )));
+
+        assert_eq!(progress.unlocked_parts, 2);
+        assert_eq!(progress.answers, vec!["1600"]);
+    }
+
+    #[test]
+    fn test_both_solved() {
+        let progress = Progress::from_html(&page(&format!(
+            "{ARTICLE_1}<p>Your puzzle answer was <code>1600</code>.</p>{ARTICLE_2}<p>Your puzzle answer was <code>8632253783011</code>.</p><p class=\"day-success\">Both parts of this puzzle are complete!</p>"
+        )));
+
+        assert_eq!(progress.unlocked_parts, 2);
+        assert_eq!(progress.answers, vec!["1600", "8632253783011"]);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::config::Config;
+use crate::error::FetchError;
+use anyhow::{Context, Result};
+use reqwest::blocking::Client;
+use scraper::{Html, Selector};
+use std::fmt;
+use std::time::Duration;
+
+/// What Advent of Code said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after a previous attempt. Holds the remaining wait
+    /// when the page states one.
+    RateLimited(Option<Duration>),
+    AlreadySolved,
+    /// The page didn't match any known response; holds the article text.
+    Unrecognized(String),
+}
+
+impl SubmitOutcome {
+    /// Process exit code for this outcome, so scripts can branch on it.
+    /// `1` is left for ordinary errors (network failures, bad arguments).
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            SubmitOutcome::Correct => 0,
+            SubmitOutcome::Incorrect => 2,
+            SubmitOutcome::TooHigh => 3,
+            SubmitOutcome::TooLow => 4,
+            SubmitOutcome::RateLimited(_) => 5,
+            SubmitOutcome::AlreadySolved => 6,
+            SubmitOutcome::Unrecognized(_) => 7,
+        }
+    }
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "⭐ That's the right answer!"),
+            SubmitOutcome::Incorrect => write!(f, "❌ That's not the right answer."),
+            SubmitOutcome::TooHigh => write!(f, "❌ That's not the right answer: too high."),
+            SubmitOutcome::TooLow => write!(f, "❌ That's not the right answer: too low."),
+            SubmitOutcome::RateLimited(Some(wait)) => write!(
+                f,
+                "⏳ You gave an answer too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            SubmitOutcome::RateLimited(None) => {
+                write!(f, "⏳ You gave an answer too recently, try again later.")
+            }
+            SubmitOutcome::AlreadySolved => {
+                write!(f, "✅ This part has already been solved.")
+            }
+            SubmitOutcome::Unrecognized(text) => write!(f, "❓ Unexpected response:\n{text}"),
+        }
+    }
+}
+
+pub fn submit_answer(
+    client: &Client,
+    config: &Config,
+    day: u32,
+    part: u32,
+    answer: &str,
+) -> Result<SubmitOutcome> {
+    let url = format!("{}/answer", config.puzzle_url(day));
+    let level = part.to_string();
+    let response = client
+        .post(&url)
+        .form(&[("level", level.as_str()), ("answer", answer)])
+        .send()
+        .map_err(FetchError::from)
+        .context("Failed to submit answer")?;
+
+    // Answers are never retried automatically, a repeated POST could count
+    // as a second guess
+    if !response.status().is_success() {
+        return Err(FetchError::from_response(&response)).context("Failed to submit answer");
+    }
+
+    let html = response
+        .text()
+        .context("Failed to read submission response")?;
+    Ok(classify_response(&html))
+}
+
+pub fn classify_response(html: &str) -> SubmitOutcome {
+    let document = Html::parse_document(html);
+    let article_selector = Selector::parse("article").unwrap();
+    let text = document
+        .select(&article_selector)
+        .next()
+        .map(|article| article.text().collect::<String>())
+        .unwrap_or_default();
+
+    if text.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            SubmitOutcome::TooHigh
+        } else if text.contains("too low") {
+            SubmitOutcome::TooLow
+        } else {
+            SubmitOutcome::Incorrect
+        }
+    } else if text.contains("You gave an answer too recently") {
+        SubmitOutcome::RateLimited(parse_wait(&text))
+    } else if text.contains("Did you already complete it") {
+        SubmitOutcome::AlreadySolved
+    } else {
+        SubmitOutcome::Unrecognized(text.trim().to_string())
+    }
+}
+
+/// Parses the "You have 1m 23s left to wait" phrase from a rate-limit page.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for token in text[start..end].split_whitespace() {
+        let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{stand_in_server, Response};
+
+    fn config(base_url: String) -> Config {
+        Config {
+            year: 2025,
+            base_url,
+        }
+    }
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_submit_answer_posts_form() {
+        let (base_url, server) = stand_in_server(vec![Response::ok(page(
+            "That's the right answer! You are one gold star closer.",
+        ))]);
+
+        let outcome = submit_answer(&Client::new(), &config(base_url), 8, 2, "25272").unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!(outcome, SubmitOutcome::Correct);
+        assert_eq!(requests[0].line, "POST /2025/day/8/answer HTTP/1.1");
+        assert_eq!(requests[0].body, "level=2&answer=25272");
+    }
+
+    #[test]
+    fn test_submit_answer_rejects_http_error() {
+        let (base_url, server) = stand_in_server(vec![Response::status(500, "oops")]);
+
+        let result = submit_answer(&Client::new(), &config(base_url), 1, 1, "3");
+        server.join().unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_classify_wrong_answers() {
+        assert_eq!(
+            classify_response(&page("That's not the right answer; your answer is too high. Please wait one minute before trying again.")),
+            SubmitOutcome::TooHigh
+        );
+        assert_eq!(
+            classify_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            SubmitOutcome::TooLow
+        );
+        assert_eq!(
+            classify_response(&page("That's not the right answer. If you're stuck, make sure you're using the full input data.")),
+            SubmitOutcome::Incorrect
+        );
+    }
+
+    #[test]
+    fn test_classify_rate_limited() {
+        assert_eq!(
+            classify_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.")),
+            SubmitOutcome::RateLimited(Some(Duration::from_secs(83)))
+        );
+        assert_eq!(
+            classify_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.")),
+            SubmitOutcome::RateLimited(Some(Duration::from_secs(34)))
+        );
+    }
+
+    #[test]
+    fn test_classify_already_solved() {
+        assert_eq!(
+            classify_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            SubmitOutcome::AlreadySolved
+        );
+    }
+
+    #[test]
+    fn test_exit_codes_are_distinct() {
+        let outcomes = [
+            SubmitOutcome::Correct,
+            SubmitOutcome::Incorrect,
+            SubmitOutcome::TooHigh,
+            SubmitOutcome::TooLow,
+            SubmitOutcome::RateLimited(None),
+            SubmitOutcome::AlreadySolved,
+            SubmitOutcome::Unrecognized(String::new()),
+        ];
+        let mut codes: Vec<i32> = outcomes.iter().map(|o| o.exit_code()).collect();
+        codes.sort();
+        codes.dedup();
+
+        assert_eq!(codes.len(), outcomes.len());
+        assert!(!codes.contains(&1));
+    }
+}